@@ -0,0 +1,73 @@
+//! Regenerates the tables of enums which mirror values in the schema from a local dump of the
+//! schema.
+//! 
+//! ```sh
+//! cargo run --features schema --bin tf2-enum-codegen -- <schema.json> [src] [--check]
+//! ```
+//! 
+//! With `--check`, files are not written and the exit code is 1 if any file would change.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use tf2_enum::schema::Schema;
+use tf2_enum::schema::codegen::Codegen;
+
+const USAGE: &str = "Usage: tf2-enum-codegen <schema.json> [src] [--check]";
+
+fn main() -> ExitCode {
+    let mut check = false;
+    let mut paths = Vec::new();
+    
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            },
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    
+    let (schema_path, src) = match paths.as_slice() {
+        [schema_path] => (schema_path.clone(), PathBuf::from("src")),
+        [schema_path, src] => (schema_path.clone(), src.clone()),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        },
+    };
+    let schema = match Schema::from_path(&schema_path) {
+        Ok(schema) => schema,
+        Err(error) => {
+            eprintln!("{}: {error}", schema_path.display());
+            return ExitCode::from(2);
+        },
+    };
+    let codegen = Codegen::new(&schema);
+    let result = if check {
+        codegen.generate_dir(&src).map(|mut files| {
+            files.retain(|file| file.changed);
+            files
+        })
+    } else {
+        codegen.write_dir(&src)
+    };
+    let changed = match result {
+        Ok(changed) => changed,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        },
+    };
+    
+    for file in &changed {
+        println!("{}", src.join(file.file_name).display());
+    }
+    
+    if check && !changed.is_empty() {
+        return ExitCode::FAILURE;
+    }
+    
+    ExitCode::SUCCESS
+}
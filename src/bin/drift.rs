@@ -0,0 +1,56 @@
+//! Compares a local dump of the schema with the values compiled into this crate.
+//! 
+//! ```sh
+//! cargo run --features schema --bin tf2-enum-drift -- <schema.json>...
+//! ```
+//! 
+//! Multiple files, such as the overview and pages of items, are merged in order. Each difference
+//! is printed on its own line and the exit code is 1 if any were found.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use tf2_enum::schema::Schema;
+use tf2_enum::schema::drift::DriftReport;
+
+const USAGE: &str = "Usage: tf2-enum-drift <schema.json>...";
+
+fn main() -> ExitCode {
+    let mut paths = Vec::new();
+    
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            },
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    
+    if paths.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    }
+    
+    let mut schema = Schema::default();
+    
+    for path in &paths {
+        match Schema::from_path(path) {
+            Ok(other) => schema.merge(other),
+            Err(error) => {
+                eprintln!("{}: {error}", path.display());
+                return ExitCode::from(2);
+            },
+        }
+    }
+    
+    let report = DriftReport::check(&schema);
+    
+    print!("{report}");
+    
+    if !report.is_empty() {
+        return ExitCode::FAILURE;
+    }
+    
+    ExitCode::SUCCESS
+}
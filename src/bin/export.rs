@@ -0,0 +1,80 @@
+//! Exports the values of each enum as lookup tables.
+//! 
+//! ```sh
+//! cargo run --features export --bin tf2-enum-export -- [--format csv|json|sql] [--out <dir>] [<table>...]
+//! ```
+//! 
+//! All tables are exported unless tables are named. With `--out`, each table is written to its own
+//! file in the directory, e.g. "qualities.csv". Otherwise the tables are printed, where JSON is an
+//! object keyed by table name.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use tf2_enum::export::{self, Format};
+
+const USAGE: &str = "Usage: tf2-enum-export [--format csv|json|sql] [--out <dir>] [<table>...]";
+
+fn main() -> ExitCode {
+    let mut format = Format::Csv;
+    let mut out = None;
+    let mut names = Vec::new();
+    let mut args = std::env::args().skip(1);
+    
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            },
+            "-f" | "--format" => {
+                let Some(parsed) = args.next().and_then(|s| s.parse().ok()) else {
+                    eprintln!("{USAGE}");
+                    return ExitCode::from(2);
+                };
+                
+                format = parsed;
+            },
+            "-o" | "--out" => {
+                let Some(dir) = args.next() else {
+                    eprintln!("{USAGE}");
+                    return ExitCode::from(2);
+                };
+                
+                out = Some(PathBuf::from(dir));
+            },
+            _ => names.push(arg),
+        }
+    }
+    
+    let mut tables = export::tables();
+    
+    if !names.is_empty() {
+        if let Some(name) = names.iter().find(|name| !tables.iter().any(|table| table.name == **name)) {
+            eprintln!("Unknown table: {name}");
+            return ExitCode::from(2);
+        }
+        
+        tables.retain(|table| names.iter().any(|name| table.name == name));
+    }
+    
+    let Some(out) = out else {
+        print!("{}", export::render(&tables, format));
+        return ExitCode::SUCCESS;
+    };
+    
+    if let Err(error) = std::fs::create_dir_all(&out) {
+        eprintln!("{}: {error}", out.display());
+        return ExitCode::from(2);
+    }
+    
+    for table in &tables {
+        let path = out.join(format!("{}.{}", table.name, format.extension()));
+        
+        if let Err(error) = std::fs::write(&path, table.render(format)) {
+            eprintln!("{}: {error}", path.display());
+            return ExitCode::from(2);
+        }
+    }
+    
+    ExitCode::SUCCESS
+}
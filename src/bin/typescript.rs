@@ -0,0 +1,43 @@
+//! Generates TypeScript definitions for the enums in this crate.
+//! 
+//! ```sh
+//! cargo run --features export --bin tf2-enum-typescript -- [<out.ts>]
+//! ```
+//! 
+//! The definitions are printed unless an output file is given.
+
+use std::process::ExitCode;
+use tf2_enum::typescript;
+
+const USAGE: &str = "Usage: tf2-enum-typescript [<out.ts>]";
+
+fn main() -> ExitCode {
+    let mut out = None;
+    
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            },
+            _ if out.is_none() => out = Some(arg),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::from(2);
+            },
+        }
+    }
+    
+    let ts = typescript::render();
+    let Some(out) = out else {
+        print!("{ts}");
+        return ExitCode::SUCCESS;
+    };
+    
+    if let Err(error) = std::fs::write(&out, ts) {
+        eprintln!("{out}: {error}");
+        return ExitCode::from(2);
+    }
+    
+    ExitCode::SUCCESS
+}
@@ -1,7 +1,7 @@
 use crate::StockWeapon;
 use serde::{Deserialize, Serialize};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Class.
 #[derive(
@@ -16,12 +16,16 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Display,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
     Clone,
     Copy,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Text))]
 #[allow(missing_docs)]
 #[repr(u32)]
 pub enum Class {
@@ -0,0 +1,258 @@
+//! Item collections and trade-up contracts.
+
+use crate::{Grade, Rarity};
+use crate::error::TradeUpError;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+
+/// An item collection, with its items grouped by [`Grade`].
+/// 
+/// Collections are not an enum as they are added frequently. They can be loaded from
+/// `items_game.txt` using [`ItemsGame`][`crate::schema::ItemsGame`] with the `schema` feature.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{Collection, Grade};
+/// 
+/// let mut collection = Collection::new("Concealed Killer Collection");
+/// 
+/// collection.insert(Grade::Commando, 15013);
+/// collection.insert(Grade::Assassin, 15000);
+/// 
+/// assert_eq!(collection.grade_of(15013), Some(Grade::Commando));
+/// assert_eq!(collection.items(Grade::Assassin), &[15000]);
+/// assert!(collection.items(Grade::Elite).is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Collection {
+    /// The name of the collection.
+    pub name: Cow<'static, str>,
+    /// The defindexes of the items in the collection, by grade.
+    #[serde(default)]
+    pub items: BTreeMap<Grade, Vec<u32>>,
+}
+
+impl Collection {
+    /// The number of items required for a trade-up contract.
+    pub const TRADE_UP_INPUTS: usize = 10;
+    
+    /// Creates an empty collection.
+    pub fn new<T: Into<Cow<'static, str>>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            items: BTreeMap::new(),
+        }
+    }
+    
+    /// Adds an item to the collection. Does nothing if the item is already in the collection at
+    /// this grade.
+    pub fn insert(&mut self, grade: Grade, defindex: u32) {
+        let items = self.items.entry(grade).or_default();
+        
+        if !items.contains(&defindex) {
+            items.push(defindex);
+        }
+    }
+    
+    /// Adds an item to the collection using its rarity.
+    pub fn insert_rarity(&mut self, rarity: Rarity, defindex: u32) {
+        self.insert(rarity.into(), defindex);
+    }
+    
+    /// Gets the items of a grade.
+    pub fn items(&self, grade: Grade) -> &[u32] {
+        self.items.get(&grade).map(Vec::as_slice).unwrap_or_default()
+    }
+    
+    /// Gets the grade of an item in the collection.
+    pub fn grade_of(&self, defindex: u32) -> Option<Grade> {
+        self.items
+            .iter()
+            .find(|(_, items)| items.contains(&defindex))
+            .map(|(grade, _)| *grade)
+    }
+    
+    /// Checks if an item is in the collection.
+    pub fn contains(&self, defindex: u32) -> bool {
+        self.grade_of(defindex).is_some()
+    }
+    
+    /// The grades which have at least one item, from lowest to highest.
+    pub fn grades(&self) -> impl Iterator<Item = Grade> + '_ {
+        self.items
+            .iter()
+            .filter(|(_, items)| !items.is_empty())
+            .map(|(grade, _)| *grade)
+    }
+    
+    /// Computes the possible outputs of a trade-up contract and their probabilities.
+    /// 
+    /// Each input is an item's defindex along with the collection it belongs to. A contract takes
+    /// [`Collection::TRADE_UP_INPUTS`] items of the same grade and produces one item of the next
+    /// grade. Each input contributes an equal chance for the output to come from its collection,
+    /// which is then split evenly between that collection's items of the next grade. The quality of
+    /// the items is not checked.
+    /// 
+    /// Outputs are returned in the order their collections first appear in the inputs. Inputs are
+    /// grouped by the collection they reference rather than its name, so distinct collections
+    /// which share a name have separate outputs.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Collection, Grade};
+    /// 
+    /// let mut a = Collection::new("A");
+    /// let mut b = Collection::new("B");
+    /// 
+    /// a.insert(Grade::Mercenary, 1);
+    /// a.insert(Grade::Commando, 2);
+    /// b.insert(Grade::Mercenary, 3);
+    /// b.insert(Grade::Commando, 4);
+    /// b.insert(Grade::Commando, 5);
+    /// 
+    /// let mut inputs = vec![(&a, 1); 6];
+    /// 
+    /// inputs.extend([(&b, 3); 4]);
+    /// 
+    /// let outcomes = Collection::trade_up(&inputs).unwrap();
+    /// 
+    /// assert_eq!(outcomes.len(), 3);
+    /// assert_eq!(outcomes[0].defindex, 2);
+    /// assert!((outcomes[0].probability - 0.6).abs() < 1e-9);
+    /// assert!((outcomes[1].probability - 0.2).abs() < 1e-9);
+    /// ```
+    pub fn trade_up<'a>(
+        inputs: &[(&'a Collection, u32)],
+    ) -> Result<Vec<TradeUpOutcome<'a>>, TradeUpError> {
+        if inputs.len() != Self::TRADE_UP_INPUTS {
+            return Err(TradeUpError::InputCount(inputs.len()));
+        }
+        
+        let grade_of = |(collection, defindex): &(&Collection, u32)| {
+            collection.grade_of(*defindex).ok_or(TradeUpError::NotInCollection(*defindex))
+        };
+        let input_grade = grade_of(&inputs[0])?;
+        
+        for input in inputs {
+            if grade_of(input)? != input_grade {
+                return Err(TradeUpError::MixedGrades);
+            }
+        }
+        
+        let grade = input_grade.next().ok_or(TradeUpError::HighestGrade)?;
+        let weight = 1.0 / inputs.len() as f64;
+        let mut outcomes: Vec<TradeUpOutcome<'a>> = Vec::new();
+        
+        for (collection, defindex) in inputs {
+            let items = collection.items(grade);
+            
+            if items.is_empty() {
+                return Err(TradeUpError::NoHigherGrade(*defindex));
+            }
+            
+            let probability = weight / items.len() as f64;
+            
+            for output in items {
+                let existing = outcomes
+                    .iter_mut()
+                    .find(|outcome| {
+                        outcome.defindex == *output &&
+                        std::ptr::eq(outcome.collection, *collection)
+                    });
+                
+                if let Some(outcome) = existing {
+                    outcome.probability += probability;
+                } else {
+                    outcomes.push(TradeUpOutcome {
+                        collection,
+                        defindex: *output,
+                        grade,
+                        probability,
+                    });
+                }
+            }
+        }
+        
+        Ok(outcomes)
+    }
+}
+
+/// A possible output of a trade-up contract.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeUpOutcome<'a> {
+    /// The collection the item comes from.
+    pub collection: &'a Collection,
+    /// The defindex of the item.
+    pub defindex: u32,
+    /// The grade of the item.
+    pub grade: Grade,
+    /// The probability of receiving the item, between 0 and 1.
+    pub probability: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn collection() -> Collection {
+        let mut collection = Collection::new("Test Collection");
+        
+        collection.insert_rarity(Rarity::Common, 1);
+        collection.insert(Grade::Freelance, 2);
+        collection.insert(Grade::Freelance, 3);
+        collection.insert(Grade::Elite, 4);
+        collection
+    }
+    
+    #[test]
+    fn trade_up_single_collection() {
+        let collection = collection();
+        let outcomes = Collection::trade_up(&[(&collection, 1); 10]).unwrap();
+        let total = outcomes.iter().map(|outcome| outcome.probability).sum::<f64>();
+        
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].grade, Grade::Freelance);
+        assert!((outcomes[0].probability - 0.5).abs() < 1e-9);
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn trade_up_separates_collections_with_the_same_name() {
+        let a = collection();
+        let b = collection();
+        let mut inputs = [(&a, 1); 10];
+        
+        inputs[5..].fill((&b, 1));
+        
+        let outcomes = Collection::trade_up(&inputs).unwrap();
+        
+        assert_eq!(outcomes.len(), 4);
+        assert!(std::ptr::eq(outcomes[0].collection, &a));
+        assert!(std::ptr::eq(outcomes[2].collection, &b));
+        assert!(outcomes.iter().all(|outcome| (outcome.probability - 0.25).abs() < 1e-9));
+    }
+    
+    #[test]
+    fn trade_up_errors() {
+        let collection = collection();
+        let mut mixed = [(&collection, 1); 10];
+        
+        mixed[9] = (&collection, 2);
+        
+        assert_eq!(Collection::trade_up(&[(&collection, 1); 9]), Err(TradeUpError::InputCount(9)));
+        assert_eq!(Collection::trade_up(&[(&collection, 5); 10]), Err(TradeUpError::NotInCollection(5)));
+        assert_eq!(Collection::trade_up(&mixed), Err(TradeUpError::MixedGrades));
+        assert_eq!(Collection::trade_up(&[(&collection, 2); 10]), Err(TradeUpError::NoHigherGrade(2)));
+        assert_eq!(Collection::trade_up(&[(&collection, 4); 10]), Err(TradeUpError::HighestGrade));
+    }
+    
+    #[test]
+    fn deserializes_collection() {
+        let json = r#"{ "name": "Test Collection", "items": { "1": [1], "2": [2, 3], "6": [4] } }"#;
+        let collection: Collection = serde_json::from_str(json).unwrap();
+        
+        assert_eq!(collection, self::collection());
+        assert_eq!(collection.grades().collect::<Vec<_>>(), vec![Grade::Civilian, Grade::Freelance, Grade::Elite]);
+    }
+}
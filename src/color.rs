@@ -0,0 +1,233 @@
+//! Color conversion and comparison utilities for hexadecimal colors.
+//! 
+//! These are used by [`Colored`][`crate::Colored`] for finding the nearest definition to an
+//! arbitrary color, but can also be used directly.
+//! 
+//! # Examples
+//! ```
+//! use tf2_enum::color;
+//! 
+//! assert_eq!(color::rgb(0xFF69B4), (255, 105, 180));
+//! assert!(color::delta_e(0xFF69B4, 0xFF69B4) == 0.0);
+//! assert!(color::delta_e(0xFF69B4, 0xFF6AB4) < 1.0);
+//! ```
+
+/// The nearest match for a color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatch<T> {
+    /// The matched value.
+    pub value: T,
+    /// The color of the value which was matched. For values with multiple colors, such as team
+    /// paints, this is the color that was closest.
+    pub color: u32,
+    /// The perceptual distance between the colors using the CIEDE2000 formula. `0.0` is an exact
+    /// match and values below `1.0` are generally imperceptible.
+    pub distance: f32,
+}
+
+/// Splits a hexadecimal color into its red, green, and blue components.
+pub fn rgb(color: u32) -> (u8, u8, u8) {
+    (
+        ((color >> 16) & 0xFF) as u8,
+        ((color >> 8) & 0xFF) as u8,
+        (color & 0xFF) as u8,
+    )
+}
+
+/// Joins red, green, and blue components into a hexadecimal color.
+pub fn from_rgb(red: u8, green: u8, blue: u8) -> u32 {
+    (red as u32) << 16 | (green as u32) << 8 | blue as u32
+}
+
+/// Converts a hexadecimal color into hue (`0.0..360.0`), saturation (`0.0..=1.0`), and lightness
+/// (`0.0..=1.0`).
+pub fn hsl(color: u32) -> (f32, f32, f32) {
+    let (red, green, blue) = rgb(color);
+    let red = red as f32 / 255.0;
+    let green = green as f32 / 255.0;
+    let blue = blue as f32 / 255.0;
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+    
+    if delta == 0.0 {
+        return (0.0, 0.0, lightness);
+    }
+    
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == red {
+        60.0 * ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / delta + 2.0)
+    } else {
+        60.0 * ((red - green) / delta + 4.0)
+    };
+    
+    (hue, saturation, lightness)
+}
+
+/// Converts a hexadecimal sRGB color into CIE L\*a\*b\* using the D65 illuminant.
+pub fn lab(color: u32) -> (f32, f32, f32) {
+    fn linear(channel: u8) -> f64 {
+        let channel = channel as f64 / 255.0;
+        
+        if channel <= 0.04045 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    
+    fn f(t: f64) -> f64 {
+        const DELTA: f64 = 6.0 / 29.0;
+        
+        if t > DELTA.powi(3) {
+            t.cbrt()
+        } else {
+            t / (3.0 * DELTA.powi(2)) + 4.0 / 29.0
+        }
+    }
+    
+    let (red, green, blue) = rgb(color);
+    let red = linear(red);
+    let green = linear(green);
+    let blue = linear(blue);
+    // D65 reference white.
+    let x = (0.4124564 * red + 0.3575761 * green + 0.1804375 * blue) / 0.95047;
+    let y = 0.2126729 * red + 0.7151522 * green + 0.0721750 * blue;
+    let z = (0.0193339 * red + 0.1191920 * green + 0.9503041 * blue) / 1.08883;
+    let fx = f(x);
+    let fy = f(y);
+    let fz = f(z);
+    
+    (
+        (116.0 * fy - 16.0) as f32,
+        (500.0 * (fx - fy)) as f32,
+        (200.0 * (fy - fz)) as f32,
+    )
+}
+
+/// Gets the perceptual distance (ΔE) between two hexadecimal colors using the CIEDE2000 formula.
+pub fn delta_e(a: u32, b: u32) -> f32 {
+    let (l1, a1, b1) = lab(a);
+    let (l2, a2, b2) = lab(b);
+    
+    ciede2000(
+        (l1 as f64, a1 as f64, b1 as f64),
+        (l2 as f64, a2 as f64, b2 as f64),
+    ) as f32
+}
+
+/// Gets the colors to match against for a definition with RED and BLU colors, in that order. This
+/// is used by [`Colored::match_colors`][`crate::Colored::match_colors`] for team colored
+/// definitions.
+pub(crate) fn team_match_colors((red, blu): (u32, u32)) -> Vec<u32> {
+    if red == blu {
+        vec![red]
+    } else {
+        vec![red, blu]
+    }
+}
+
+// See: <https://en.wikipedia.org/wiki/Color_difference#CIEDE2000>
+fn ciede2000(
+    (l1, a1, b1): (f64, f64, f64),
+    (l2, a2, b2): (f64, f64, f64),
+) -> f64 {
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let c_bar = (c1 + c2) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + 25f64.powi(7))).sqrt());
+    let a1 = (1.0 + g) * a1;
+    let a2 = (1.0 + g) * a2;
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let hue = |a: f64, b: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1 = hue(a1, b1);
+    let h2 = hue(a2, b2);
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+    let t = 1.0
+        - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + 25f64.powi(7))).sqrt();
+    let s_l = 1.0 + (0.015 * (l_bar - 50.0).powi(2)) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_h / s_h;
+    
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.0001
+    }
+    
+    #[test]
+    fn converts_rgb() {
+        assert_eq!(rgb(0x4D7455), (0x4D, 0x74, 0x55));
+        assert_eq!(from_rgb(0x4D, 0x74, 0x55), 0x4D7455);
+    }
+    
+    #[test]
+    fn converts_hsl() {
+        assert_eq!(hsl(0xFF0000), (0.0, 1.0, 0.5));
+        assert_eq!(hsl(0x00FF00), (120.0, 1.0, 0.5));
+        assert_eq!(hsl(0x808080).1, 0.0);
+    }
+    
+    #[test]
+    fn converts_lab() {
+        let (l, a, b) = lab(0xFFFFFF);
+        
+        assert!((l - 100.0).abs() < 0.01);
+        assert!(a.abs() < 0.01);
+        assert!(b.abs() < 0.01);
+    }
+    
+    #[test]
+    fn ciede2000_reference_values() {
+        // From Sharma, Wu, and Dalal's CIEDE2000 test data.
+        assert!(approx_eq(ciede2000((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485)), 2.0425));
+        assert!(approx_eq(ciede2000((50.0, 2.5, 0.0), (73.0, 25.0, -18.0)), 27.1492));
+        assert!(approx_eq(ciede2000((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514)), 0.9082));
+    }
+}
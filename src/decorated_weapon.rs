@@ -0,0 +1,108 @@
+//! A view over the attributes of a Decorated Weapon.
+
+use crate::{
+    Attribute,
+    Grade,
+    ItemAttribute,
+    PaintKit,
+    PaintKitRegistry,
+    PaintKitSeed,
+    TryFromIntAttributeValue,
+    Wear,
+};
+
+/// The paint kit, wear, seed, and grade of a Decorated Weapon or War Paint, read from its
+/// attributes.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{DecoratedWeapon, Grade, ItemAttribute, PaintKit, PaintKitRegistry, PaintKitSeed, Wear};
+/// use tf2_enum::econ_attributes::PaintkitProtoDefIndex;
+/// 
+/// let registry = PaintKitRegistry::from_iter([PaintKit {
+///     grade: Some(Grade::Commando),
+///     ..PaintKit::new(200, "Sweet Dreams")
+/// }]);
+/// let mut attributes = vec![
+///     ItemAttribute::from(PaintkitProtoDefIndex(200)),
+///     ItemAttribute::from(Wear::FieldTested),
+/// ];
+/// 
+/// attributes.extend(PaintKitSeed(1234567890123).to_attributes());
+/// 
+/// let decorated = DecoratedWeapon::from_attributes(&attributes, &registry).unwrap();
+/// 
+/// assert_eq!(decorated.paint_kit.unwrap().name, "Sweet Dreams");
+/// assert_eq!(decorated.wear, Some(Wear::FieldTested));
+/// assert_eq!(decorated.seed, Some(PaintKitSeed(1234567890123)));
+/// assert_eq!(decorated.grade, Some(Grade::Commando));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecoratedWeapon<'a> {
+    /// The ID of the paint kit.
+    pub paint_kit_id: u32,
+    /// The paint kit, if it is in the registry.
+    pub paint_kit: Option<&'a PaintKit>,
+    /// The wear.
+    pub wear: Option<Wear>,
+    /// The seed used for generating the pattern.
+    pub seed: Option<PaintKitSeed>,
+    /// The grade, taken from the paint kit.
+    pub grade: Option<Grade>,
+}
+
+impl<'a> DecoratedWeapon<'a> {
+    /// Reads a Decorated Weapon from a list of attributes, looking up its paint kit in `registry`.
+    /// Returns `None` if the attributes do not include a paint kit.
+    pub fn from_attributes(
+        attributes: &[ItemAttribute],
+        registry: &'a PaintKitRegistry,
+    ) -> Option<Self> {
+        let paint_kit_id = attributes.iter().find_map(PaintKit::id_from_attribute)?;
+        let paint_kit = registry.get(paint_kit_id);
+        let wear = attributes
+            .iter()
+            .find(|attribute| attribute.defindex == Wear::DEFINDEX)
+            .and_then(|attribute| attribute.float_value)
+            .and_then(Wear::try_from_attribute_float_value);
+        
+        Some(Self {
+            paint_kit_id,
+            paint_kit,
+            wear,
+            seed: PaintKitSeed::from_attributes(attributes),
+            grade: paint_kit.and_then(|paint_kit| paint_kit.grade),
+        })
+    }
+    
+    /// Gets the name of the paint kit, if it is in the registry.
+    pub fn name(&self) -> Option<&'a str> {
+        self.paint_kit.map(|paint_kit| paint_kit.name.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::econ_attributes::PaintkitProtoDefIndex;
+    
+    #[test]
+    fn unknown_paint_kit() {
+        let registry = PaintKitRegistry::new();
+        let attributes = [ItemAttribute::from(PaintkitProtoDefIndex(999))];
+        let decorated = DecoratedWeapon::from_attributes(&attributes, &registry).unwrap();
+        
+        assert_eq!(decorated.paint_kit_id, 999);
+        assert_eq!(decorated.name(), None);
+        assert_eq!(decorated.wear, None);
+        assert_eq!(decorated.seed, None);
+        assert_eq!(decorated.grade, None);
+    }
+    
+    #[test]
+    fn not_decorated() {
+        let registry = PaintKitRegistry::new();
+        
+        assert!(DecoratedWeapon::from_attributes(&[ItemAttribute::from(Wear::FactoryNew)], &registry).is_none());
+    }
+}
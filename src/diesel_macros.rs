@@ -0,0 +1,176 @@
+/// Macro to implement diesel's `FromSql` for a type that implements FromStr as `Text` on any
+/// backend. The type must also derive `AsExpression` and `FromSqlRow` with
+/// `#[diesel(sql_type = Text)]`.
+#[macro_export]
+macro_rules! impl_diesel_enum_display {
+    ($t:ty) => {
+        impl<DB> ::diesel::deserialize::FromSql<::diesel::sql_types::Text, DB> for $t
+        where
+            DB: ::diesel::backend::Backend,
+            String: ::diesel::deserialize::FromSql<::diesel::sql_types::Text, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> ::diesel::deserialize::Result<Self> {
+                let s = <String as ::diesel::deserialize::FromSql<::diesel::sql_types::Text, DB>>::from_sql(bytes)?;
+                
+                Ok(s.parse()?)
+            }
+        }
+    };
+}
+
+/// Macro to implement diesel's `ToSql` for a type that implements Display as Postgres `Text`.
+#[macro_export]
+macro_rules! impl_diesel_enum_display_postgres {
+    ($t:ty) => {
+        impl ::diesel::serialize::ToSql<::diesel::sql_types::Text, ::diesel::pg::Pg> for $t {
+            fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>) -> ::diesel::serialize::Result {
+                use ::std::io::Write;
+                
+                out.write_all(self.to_string().as_bytes())?;
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+    };
+}
+
+/// Macro to implement diesel's `ToSql` for a type that implements Display as SQLite `Text`.
+#[macro_export]
+macro_rules! impl_diesel_enum_display_sqlite {
+    ($t:ty) => {
+        impl ::diesel::serialize::ToSql<::diesel::sql_types::Text, ::diesel::sqlite::Sqlite> for $t {
+            fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::sqlite::Sqlite>) -> ::diesel::serialize::Result {
+                out.set_value(self.to_string());
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+    };
+}
+
+/// Macro to implement diesel's `FromSql` for enums with repr(u32) as `Integer` on any backend.
+/// The type must also derive `AsExpression` and `FromSqlRow` with
+/// `#[diesel(sql_type = Integer)]`.
+#[macro_export]
+macro_rules! impl_diesel_enum_repr {
+    ($t:ty) => {
+        impl<DB> ::diesel::deserialize::FromSql<::diesel::sql_types::Integer, DB> for $t
+        where
+            DB: ::diesel::backend::Backend,
+            i32: ::diesel::deserialize::FromSql<::diesel::sql_types::Integer, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> ::diesel::deserialize::Result<Self> {
+                let v = <i32 as ::diesel::deserialize::FromSql<::diesel::sql_types::Integer, DB>>::from_sql(bytes)?;
+                
+                <$t as ::std::convert::TryFrom<u32>>::try_from(v as u32)
+                    .map_err(|_| format!("Invalid enum value for {}: {}", stringify!($t), v).into())
+            }
+        }
+    };
+}
+
+/// Macro to implement diesel's `ToSql` for enums with repr(u32) as Postgres `Integer`.
+#[macro_export]
+macro_rules! impl_diesel_enum_repr_postgres {
+    ($t:ty) => {
+        impl ::diesel::serialize::ToSql<::diesel::sql_types::Integer, ::diesel::pg::Pg> for $t {
+            fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>) -> ::diesel::serialize::Result {
+                let v = *self as u32 as i32;
+                <i32 as ::diesel::serialize::ToSql<::diesel::sql_types::Integer, ::diesel::pg::Pg>>::to_sql(&v, &mut out.reborrow())
+            }
+        }
+    };
+}
+
+/// Macro to implement diesel's `ToSql` for enums with repr(u32) as SQLite `Integer`.
+#[macro_export]
+macro_rules! impl_diesel_enum_repr_sqlite {
+    ($t:ty) => {
+        impl ::diesel::serialize::ToSql<::diesel::sql_types::Integer, ::diesel::sqlite::Sqlite> for $t {
+            fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::sqlite::Sqlite>) -> ::diesel::serialize::Result {
+                out.set_value(*self as u32 as i32);
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+    };
+}
+
+#[cfg(all(test, feature = "diesel-2-sqlite"))]
+mod tests {
+    use crate::{Class, ItemLevel, Paint, Quality, StrangePart};
+    use diesel::prelude::*;
+    use diesel::sql_types::{Integer, Text};
+    use diesel::sqlite::SqliteConnection;
+    
+    diesel::table! {
+        items (id) {
+            id -> Integer,
+            quality -> Integer,
+            class -> Text,
+            paint -> Nullable<Integer>,
+            item_level -> Nullable<Text>,
+        }
+    }
+    
+    fn connect() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        
+        diesel::sql_query(
+            "CREATE TABLE items (
+                id INTEGER PRIMARY KEY NOT NULL,
+                quality INTEGER NOT NULL,
+                class TEXT NOT NULL,
+                paint INTEGER,
+                item_level TEXT
+            )",
+        )
+            .execute(&mut conn)
+            .unwrap();
+        
+        conn
+    }
+    
+    #[test]
+    fn round_trips_sqlite() {
+        let mut conn = connect();
+        
+        diesel::insert_into(items::table)
+            .values((
+                items::id.eq(1),
+                items::quality.eq(Quality::Strange),
+                items::class.eq(&Class::Scout),
+                items::paint.eq(Some(Paint::AustraliumGold)),
+                items::item_level.eq(None::<ItemLevel>),
+            ))
+            .execute(&mut conn)
+            .unwrap();
+        
+        let row = items::table
+            .select((items::quality, items::class, items::paint, items::item_level))
+            .filter(items::quality.eq(Quality::Strange))
+            .first::<(Quality, Class, Option<Paint>, Option<ItemLevel>)>(&mut conn)
+            .unwrap();
+        
+        assert_eq!(row, (Quality::Strange, Class::Scout, Some(Paint::AustraliumGold), None));
+    }
+    
+    #[test]
+    fn stores_plain_values_sqlite() {
+        let mut conn = connect();
+        let (strange_part, class) = diesel::select((
+            StrangePart::CriticalKills.into_sql::<Integer>(),
+            Class::Heavy.into_sql::<Text>(),
+        ))
+            .get_result::<(i32, String)>(&mut conn)
+            .unwrap();
+        
+        assert_eq!(strange_part, StrangePart::CriticalKills as i32);
+        assert_eq!(class, "Heavy");
+    }
+    
+    #[test]
+    fn rejects_unknown_values_sqlite() {
+        let mut conn = connect();
+        
+        assert!(diesel::select(9999.into_sql::<Integer>()).get_result::<Quality>(&mut conn).is_err());
+        assert!(diesel::select("Unknown".into_sql::<Text>()).get_result::<Class>(&mut conn).is_err());
+    }
+}
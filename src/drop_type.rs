@@ -1,5 +1,5 @@
  use serde::{Deserialize, Serialize};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Drop type.
 #[derive(
@@ -16,8 +16,12 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Serialize,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Text))]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
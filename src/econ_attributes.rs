@@ -642,6 +642,42 @@ impl_attr!(
     true
 );
 
+/// Represents the "custom_paintkit_seed_lo" attribute. This is the lower 32 bits of the seed
+/// used for generating a War Paint's pattern. Refer to [`PaintKitSeed`][`crate::PaintKitSeed`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomPaintkitSeedLo(pub u32);
+
+impl_attr!(
+    u32,
+    CustomPaintkitSeedLo,
+    866,
+    "custom_paintkit_seed_lo",
+    Some("custom_paintkit_seed_lo"),
+    None,
+    Some(DescriptionFormat::ValueIsAdditive),
+    EffectType::Neutral,
+    true,
+    true
+);
+
+/// Represents the "custom_paintkit_seed_hi" attribute. This is the upper 32 bits of the seed
+/// used for generating a War Paint's pattern. Refer to [`PaintKitSeed`][`crate::PaintKitSeed`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomPaintkitSeedHi(pub u32);
+
+impl_attr!(
+    u32,
+    CustomPaintkitSeedHi,
+    867,
+    "custom_paintkit_seed_hi",
+    Some("custom_paintkit_seed_hi"),
+    None,
+    Some(DescriptionFormat::ValueIsAdditive),
+    EffectType::Neutral,
+    true,
+    true
+);
+
 /// Represents the "tool_target_item" attribute.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ToolTargetItem(pub u32);
@@ -1282,6 +1318,7 @@ mod tests {
     fn supply_crate_series_try_from() {
         let series_number = 57u32;
         // Verifies that we can use the TryFrom trait to create a SupplyCrateSeries from a u32.
+        #[allow(clippy::unnecessary_fallible_conversions)]
         let supply_crate_series = SupplyCrateSeries::try_from(series_number).unwrap();
         
         assert_eq!(supply_crate_series.0, series_number);
@@ -1,6 +1,6 @@
 //! Provides error types.
 
-use crate::Spell;
+use crate::{Class, Grade, ItemSlot, Spell};
 use std::fmt;
 
 pub use strum::ParseError;
@@ -42,3 +42,317 @@ impl fmt::Display for InsertError {
 }
 
 impl std::error::Error for InsertError {}
+
+/// The reason a strange part cannot be applied to an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StrangePartApplicabilityError {
+    /// The strange part can only be applied to cosmetics.
+    CosmeticsOnly,
+    /// The strange part can only be applied to weapons.
+    WeaponsOnly,
+    /// The strange part can only be applied to items in these slots.
+    ItemSlots(&'static [ItemSlot]),
+    /// The strange part can only be applied to items used by these classes.
+    Classes(&'static [Class]),
+}
+
+impl fmt::Display for StrangePartApplicabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrangePartApplicabilityError::CosmeticsOnly => {
+                write!(f, "Strange part can only be applied to cosmetics")
+            },
+            StrangePartApplicabilityError::WeaponsOnly => {
+                write!(f, "Strange part can only be applied to weapons")
+            },
+            StrangePartApplicabilityError::ItemSlots(item_slots) => {
+                write!(f, "Strange part can only be applied to items in slots: {}", join(item_slots))
+            },
+            StrangePartApplicabilityError::Classes(classes) => {
+                write!(f, "Strange part can only be applied to items used by classes: {}", join(classes))
+            },
+        }
+    }
+}
+
+impl std::error::Error for StrangePartApplicabilityError {}
+
+fn join<T: fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The reason a spell cannot be applied to an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpellApplicabilityError {
+    /// The spell can only be applied to cosmetics.
+    CosmeticsOnly,
+    /// The spell can only be applied to weapons.
+    WeaponsOnly,
+    /// The spell can only be applied to paintable items.
+    NotPaintable,
+}
+
+impl fmt::Display for SpellApplicabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpellApplicabilityError::CosmeticsOnly => write!(f, "Spell can only be applied to cosmetics"),
+            SpellApplicabilityError::WeaponsOnly => write!(f, "Spell can only be applied to weapons"),
+            SpellApplicabilityError::NotPaintable => write!(f, "Spell can only be applied to paintable items"),
+        }
+    }
+}
+
+impl std::error::Error for SpellApplicabilityError {}
+
+/// An error inserting a spell into a [`SpellSet`][`crate::SpellSet`] for an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpellInsertError {
+    /// The spell could not be inserted into the set.
+    Insert(InsertError),
+    /// The spell cannot be applied to the item.
+    Applicability(SpellApplicabilityError),
+}
+
+impl fmt::Display for SpellInsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpellInsertError::Insert(error) => write!(f, "{error}"),
+            SpellInsertError::Applicability(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for SpellInsertError {}
+
+impl From<InsertError> for SpellInsertError {
+    fn from(error: InsertError) -> Self {
+        SpellInsertError::Insert(error)
+    }
+}
+
+impl From<SpellApplicabilityError> for SpellInsertError {
+    fn from(error: SpellApplicabilityError) -> Self {
+        SpellInsertError::Applicability(error)
+    }
+}
+
+/// The reason a trade-up contract is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TradeUpError {
+    /// The contract does not contain the required number of items.
+    InputCount(usize),
+    /// The item is not in the collection it was submitted with.
+    NotInCollection(u32),
+    /// The items are not all of the same grade.
+    MixedGrades,
+    /// The items are already of the highest grade.
+    HighestGrade,
+    /// The item's collection has no items of the next grade.
+    NoHigherGrade(u32),
+}
+
+impl fmt::Display for TradeUpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TradeUpError::InputCount(count) => {
+                write!(f, "Trade-up requires {} items, got {count}", crate::Collection::TRADE_UP_INPUTS)
+            },
+            TradeUpError::NotInCollection(defindex) => {
+                write!(f, "Item `{defindex}` is not in its collection")
+            },
+            TradeUpError::MixedGrades => write!(f, "Trade-up items must all be of the same grade"),
+            TradeUpError::HighestGrade => write!(f, "Trade-up items are already of grade {}", Grade::Elite),
+            TradeUpError::NoHigherGrade(defindex) => {
+                write!(f, "Collection of item `{defindex}` has no items of a higher grade")
+            },
+        }
+    }
+}
+
+impl std::error::Error for TradeUpError {}
+
+/// An error converting an attribute into an item of an attribute set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeValueError {
+    /// The attribute does not have a float value.
+    MissingFloatValue,
+    /// The float value does not correspond to a known value.
+    UnknownValue,
+}
+
+impl fmt::Display for AttributeValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeValueError::MissingFloatValue => write!(f, "Attribute is missing float_value"),
+            AttributeValueError::UnknownValue => write!(f, "Cannot convert from float_value"),
+        }
+    }
+}
+
+impl std::error::Error for AttributeValueError {}
+
+/// An error loading a schema.
+#[cfg(feature = "schema")]
+#[derive(Debug)]
+pub enum SchemaError {
+    /// The schema could not be read.
+    Io(std::io::Error),
+    /// The schema could not be parsed.
+    Json(serde_json::Error),
+}
+
+#[cfg(feature = "schema")]
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::Io(error) => write!(f, "Error reading schema: {error}"),
+            SchemaError::Json(error) => write!(f, "Error parsing schema: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl std::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SchemaError::Io(error) => Some(error),
+            SchemaError::Json(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl From<std::io::Error> for SchemaError {
+    fn from(error: std::io::Error) -> Self {
+        SchemaError::Io(error)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl From<serde_json::Error> for SchemaError {
+    fn from(error: serde_json::Error) -> Self {
+        SchemaError::Json(error)
+    }
+}
+
+/// An error parsing or deserializing KeyValues (VDF).
+#[cfg(feature = "schema")]
+#[derive(Debug)]
+pub enum VdfError {
+    /// The input is not valid KeyValues.
+    Syntax {
+        /// The line the error occurred on, starting at 1.
+        line: usize,
+        /// The column the error occurred on, starting at 1.
+        column: usize,
+        /// A description of the error.
+        message: String,
+    },
+    /// A file could not be read.
+    Io(std::io::Error),
+    /// A `#base` or `#include` directive was found but no include directory was given.
+    UnresolvedInclude(String),
+    /// A file includes itself, directly or through other files.
+    IncludeCycle(std::path::PathBuf),
+    /// `#base` and `#include` directives are nested too deeply.
+    IncludeTooDeep(std::path::PathBuf),
+    /// The value could not be deserialized.
+    Message(String),
+}
+
+#[cfg(feature = "schema")]
+impl fmt::Display for VdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VdfError::Syntax { line, column, message } => {
+                write!(f, "Syntax error at line {line}, column {column}: {message}")
+            },
+            VdfError::Io(error) => write!(f, "Error reading file: {error}"),
+            VdfError::UnresolvedInclude(path) => write!(f, "Cannot resolve include `{path}` without an include directory"),
+            VdfError::IncludeCycle(path) => write!(f, "Include cycle at `{}`", path.display()),
+            VdfError::IncludeTooDeep(path) => write!(f, "Includes are nested too deeply at `{}`", path.display()),
+            VdfError::Message(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl std::error::Error for VdfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VdfError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl serde::de::Error for VdfError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        VdfError::Message(message.to_string())
+    }
+}
+
+#[cfg(feature = "schema")]
+impl From<std::io::Error> for VdfError {
+    fn from(error: std::io::Error) -> Self {
+        VdfError::Io(error)
+    }
+}
+
+/// An error regenerating source files from a schema.
+#[cfg(feature = "schema")]
+#[derive(Debug)]
+pub enum CodegenError {
+    /// A file could not be read or written.
+    Io(std::io::Error),
+    /// A source file was not given.
+    MissingFile(String),
+    /// A source file does not contain the markers for a generated region.
+    MissingRegion {
+        /// The name of the file.
+        file: String,
+        /// The name of the region.
+        region: &'static str,
+    },
+    /// A kill eater score type uses an item level set which is not known.
+    UnknownItemLevel(String),
+    /// A variant has no value in one of its tables.
+    MissingValue(String),
+}
+
+#[cfg(feature = "schema")]
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodegenError::Io(error) => write!(f, "Error accessing file: {error}"),
+            CodegenError::MissingFile(file) => write!(f, "Missing source file `{file}`"),
+            CodegenError::MissingRegion { file, region } => {
+                write!(f, "Missing generated region `{region}` in `{file}`")
+            },
+            CodegenError::UnknownItemLevel(name) => write!(f, "Unknown item level `{name}`"),
+            CodegenError::MissingValue(variant) => write!(f, "Missing table value for `{variant}`"),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl std::error::Error for CodegenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CodegenError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl From<std::io::Error> for CodegenError {
+    fn from(error: std::io::Error) -> Self {
+        CodegenError::Io(error)
+    }
+}
@@ -0,0 +1,611 @@
+//! Exports the values of each enum as lookup tables for loading into a database or warehouse.
+//! 
+//! Each table has a row for each variant, with its integer representation as `id` for repr enums,
+//! its name as `name`, and the other names accepted when parsing it as `aliases`. The remaining
+//! columns hold extra data such as colors, attribute defindexes, and levels. Lists and nested
+//! values are written as JSON, which is embedded as a string in CSV and SQL.
+//! 
+//! # Examples
+//! ```
+//! use tf2_enum::export::{self, Format};
+//! 
+//! let table = export::table("qualities").unwrap();
+//! let csv = table.render(Format::Csv);
+//! 
+//! assert_eq!(csv.lines().next(), Some("id,name,aliases,color"));
+//! assert_eq!(csv.lines().nth(1), Some("0,Normal,[],#B2B2B2"));
+//! ```
+
+use crate::{
+    Attribute,
+    Attributes,
+    Capability,
+    Class,
+    Colored,
+    CraftClass,
+    CraftMaterialType,
+    DescriptionFormat,
+    DropType,
+    EffectType,
+    Flags,
+    FootprintsSpell,
+    GCItemSort,
+    Grade,
+    HasItemDefindex,
+    HolidayRestriction,
+    ItemLevel,
+    ItemSlot,
+    KillEaterScoreType,
+    KillstreakTier,
+    Killstreaker,
+    Origin,
+    Paint,
+    PaintSpell,
+    Quality,
+    Rarity,
+    Sheen,
+    Spell,
+    StockWeapon,
+    StrangePart,
+    Team,
+    Wear,
+};
+use std::fmt;
+use serde_json::json;
+use strum::{Display, EnumIter, EnumMessage, EnumString, IntoEnumIterator};
+
+/// An output format for tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+#[strum(serialize_all = "lowercase")]
+pub enum Format {
+    /// Comma-separated values with a header row.
+    Csv,
+    /// A JSON array of objects keyed by column.
+    Json,
+    /// SQL `INSERT` statements.
+    Sql,
+}
+
+impl Format {
+    /// Gets the file extension for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Sql => "sql",
+        }
+    }
+}
+
+/// A value in a table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// No value.
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// An integer.
+    Integer(i64),
+    /// A float.
+    Float(f32),
+    /// A string.
+    Text(String),
+    /// A list or nested value.
+    Json(serde_json::Value),
+}
+
+impl Value {
+    /// Converts this into a JSON value.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Null => serde_json::Value::Null,
+            Self::Bool(value) => json!(value),
+            Self::Integer(value) => json!(value),
+            // Converting through the shortest representation keeps 0.2 from becoming
+            // 0.20000000298023224.
+            Self::Float(value) => json!(value.to_string().parse::<f64>().unwrap_or_default()),
+            Self::Text(value) => json!(value),
+            Self::Json(value) => value.clone(),
+        }
+    }
+    
+    /// Formats this as an SQL literal.
+    fn to_sql(&self) -> String {
+        match self {
+            Self::Null => "NULL".into(),
+            Self::Bool(true) => "TRUE".into(),
+            Self::Bool(false) => "FALSE".into(),
+            Self::Integer(value) => value.to_string(),
+            Self::Float(value) => value.to_string(),
+            Self::Text(value) => quote_sql(value),
+            Self::Json(value) => quote_sql(&value.to_string()),
+        }
+    }
+    
+    /// Formats this as a CSV field.
+    fn to_csv(&self) -> String {
+        let s = match self {
+            Self::Null => return String::new(),
+            Self::Text(value) => value.clone(),
+            Self::Json(value) => value.to_string(),
+            _ => self.to_string(),
+        };
+        
+        if s.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => Ok(()),
+            Self::Bool(value) => value.fmt(f),
+            Self::Integer(value) => value.fmt(f),
+            Self::Float(value) => value.fmt(f),
+            Self::Text(value) => value.fmt(f),
+            Self::Json(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<serde_json::Value> for Value {
+    fn from(value: serde_json::Value) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Null)
+    }
+}
+
+/// A lookup table of the values of an enum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    /// The name of the table, e.g. "qualities".
+    pub name: &'static str,
+    /// The names of the columns.
+    pub columns: Vec<&'static str>,
+    /// The rows, with a value for each column.
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    /// Renders this table in the given format.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Csv => self.to_csv(),
+            Format::Json => serde_json::to_string_pretty(&self.to_json())
+                .expect("JSON values are always serializable"),
+            Format::Sql => self.to_sql(),
+        }
+    }
+    
+    /// Converts this table into a JSON array of objects keyed by column.
+    pub fn to_json(&self) -> serde_json::Value {
+        self.rows
+            .iter()
+            .map(|row| self.columns
+                .iter()
+                .zip(row)
+                .map(|(column, value)| (column.to_string(), value.to_json()))
+                .collect::<serde_json::Map<_, _>>())
+            .collect()
+    }
+    
+    /// Converts this table into CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = self.columns.join(",");
+        
+        csv.push('\n');
+        
+        for row in &self.rows {
+            let fields = row
+                .iter()
+                .map(Value::to_csv)
+                .collect::<Vec<_>>();
+            
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        
+        csv
+    }
+    
+    /// Converts this table into an SQL `INSERT` statement with a row for each value. Returns an
+    /// empty string if there are no rows.
+    pub fn to_sql(&self) -> String {
+        if self.rows.is_empty() {
+            return String::new();
+        }
+        
+        let rows = self.rows
+            .iter()
+            .map(|row| {
+                let values = row
+                    .iter()
+                    .map(Value::to_sql)
+                    .collect::<Vec<_>>();
+                
+                format!("    ({})", values.join(", "))
+            })
+            .collect::<Vec<_>>();
+        
+        format!(
+            "INSERT INTO {} ({}) VALUES\n{};\n",
+            self.name,
+            self.columns.join(", "),
+            rows.join(",\n"),
+        )
+    }
+}
+
+/// Renders multiple tables in the given format. JSON is rendered as an object keyed by table name
+/// and SQL as consecutive statements. CSV can only hold one table, so each table is separated by
+/// a blank line.
+pub fn render(tables: &[Table], format: Format) -> String {
+    match format {
+        Format::Json => {
+            let object = tables
+                .iter()
+                .map(|table| (table.name.to_string(), table.to_json()))
+                .collect::<serde_json::Map<_, _>>();
+            
+            serde_json::to_string_pretty(&object).expect("JSON values are always serializable")
+        },
+        Format::Csv | Format::Sql => tables
+            .iter()
+            .map(|table| table.render(format))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Gets a table by name.
+pub fn table(name: &str) -> Option<Table> {
+    tables().into_iter().find(|table| table.name == name)
+}
+
+/// Gets the tables for every enum.
+pub fn tables() -> Vec<Table> {
+    macro_rules! tables {
+        ($($t:ident => $name:literal),* $(,)?) => {
+            vec![$(<crate::$t as ExportTable>::table()),*]
+        };
+    }
+    
+    for_each_enum!(tables)
+}
+
+/// An enum with a lookup table.
+trait ExportTable {
+    /// Builds the table.
+    fn table() -> Table;
+}
+
+/// Implements [`ExportTable`] for enums with the given tables.
+macro_rules! impl_export_table {
+    ($($t:ident => $table:expr),* $(,)?) => {
+        $(
+            impl ExportTable for $t {
+                fn table() -> Table {
+                    $table
+                }
+            }
+        )*
+    };
+}
+
+impl_export_table! {
+    Capability => enum_table::<Capability>("capabilities", &[], |_| vec![]),
+    Class => repr_table::<Class>("classes", &["stock_weapons"], |class| vec![
+        names(class.stock_weapons()),
+    ]),
+    CraftClass => enum_table::<CraftClass>("craft_classes", &[], |_| vec![]),
+    CraftMaterialType => enum_table::<CraftMaterialType>("craft_material_types", &[], |_| vec![]),
+    DescriptionFormat => enum_table::<DescriptionFormat>("description_formats", &[], |_| vec![]),
+    DropType => enum_table::<DropType>("drop_types", &[], |_| vec![]),
+    EffectType => enum_table::<EffectType>("effect_types", &[], |_| vec![]),
+    Flags => repr_table::<Flags>("flags", &[], |_| vec![]),
+    FootprintsSpell => repr_table::<FootprintsSpell>("footprints_spells", &["color", "attribute_defindex"], |spell| vec![
+        spell.color().map(color).into(),
+        FootprintsSpell::DEFINDEX.into(),
+    ]),
+    GCItemSort => repr_table::<GCItemSort>("gc_item_sorts", &[], |_| vec![]),
+    Grade => repr_table::<Grade>("grades", &["color"], |grade| vec![
+        color(grade.color()),
+    ]),
+    HolidayRestriction => enum_table::<HolidayRestriction>("holiday_restrictions", &[], |_| vec![]),
+    ItemLevel => enum_table::<ItemLevel>("item_levels", &["levels"], |item_level| {
+        let levels = item_level
+            .levels()
+            .iter()
+            .map(|level| json!({
+                "level": level.level,
+                "required_score": level.required_score,
+                "name": level.name,
+            }))
+            .collect::<Vec<_>>();
+        
+        vec![json!(levels).into()]
+    }),
+    ItemSlot => enum_table::<ItemSlot>("item_slots", &["is_weapon", "is_cosmetic"], |item_slot| vec![
+        item_slot.is_weapon().into(),
+        item_slot.is_cosmetic().into(),
+    ]),
+    KillEaterScoreType => repr_table::<KillEaterScoreType>(
+        "kill_eater_score_types",
+        &["item_level", "strange_part_id"],
+        |score_type| vec![
+            score_type.item_level().to_string().into(),
+            score_type.strange_part().map(u32::from).into(),
+        ],
+    ),
+    KillstreakTier => repr_table::<KillstreakTier>("killstreak_tiers", &["attribute_defindex"], |_| vec![
+        KillstreakTier::DEFINDEX.into(),
+    ]),
+    Killstreaker => repr_table::<Killstreaker>("killstreakers", &["attribute_defindex"], |_| vec![
+        Killstreaker::DEFINDEX.into(),
+    ]),
+    Origin => repr_table::<Origin>("origins", &[], |_| vec![]),
+    Paint => repr_table::<Paint>(
+        "paints",
+        &["color", "color_red", "color_blu", "is_team_paint", "defindex", "attribute_defindex"],
+        |paint| {
+            let (red, blu) = paint.colors();
+            
+            vec![
+                color(paint.color()),
+                color(red),
+                color(blu),
+                paint.is_team_paint().into(),
+                paint.defindex().into(),
+                Paint::DEFINDEX.into(),
+            ]
+        },
+    ),
+    PaintSpell => repr_table::<PaintSpell>("paint_spells", &["is_team_colored", "attribute_defindex"], |spell| vec![
+        spell.is_team_colored().into(),
+        PaintSpell::DEFINDEX.into(),
+    ]),
+    Quality => repr_table::<Quality>("qualities", &["color"], |quality| vec![
+        color(quality.color()),
+    ]),
+    Rarity => repr_table::<Rarity>("rarities", &[], |_| vec![]),
+    Sheen => repr_table::<Sheen>("sheens", COLORED_ATTRIBUTE_COLUMNS, |sheen| {
+        let (red, blu) = sheen.colors();
+        
+        vec![
+            color(sheen.color()),
+            color(red),
+            color(blu),
+            Sheen::DEFINDEX.into(),
+        ]
+    }),
+    Spell => enum_table::<Spell>(
+        "spells",
+        &["attribute_defindex", "attribute_id", "color"],
+        |spell| vec![
+            spell.attribute_defindex().into(),
+            spell.attribute_id().into(),
+            spell.color().map(color).into(),
+        ],
+    ),
+    StockWeapon => enum_table::<StockWeapon>(
+        "stock_weapons",
+        &["defindexes", "econ_defindex", "item_slot", "classes"],
+        |stock_weapon| vec![
+            json!(stock_weapon.defindexes()).into(),
+            stock_weapon.econ_defindex().into(),
+            stock_weapon.item_slot().to_string().into(),
+            names(stock_weapon.used_by_classes()),
+        ],
+    ),
+    StrangePart => repr_table::<StrangePart>(
+        "strange_parts",
+        &["strange_part_name", "defindex", "attribute_defindexes"],
+        |strange_part| vec![
+            strange_part.strange_part_name().into(),
+            strange_part.defindex().into(),
+            json!(StrangePart::DEFINDEX).into(),
+        ],
+    ),
+    Team => repr_table::<Team>("teams", &[], |_| vec![]),
+    Wear => repr_table::<Wear>("wears", &["float_value", "attribute_defindex"], |wear| vec![
+        wear.as_float().into(),
+        Wear::DEFINDEX.into(),
+    ]),
+}
+
+/// The extra columns for team colored attributes.
+const COLORED_ATTRIBUTE_COLUMNS: &[&str] = &["color", "color_red", "color_blu", "attribute_defindex"];
+
+/// Builds a table with a row for each variant, starting with the name and aliases of the variant
+/// followed by `columns`.
+fn enum_table<T>(
+    name: &'static str,
+    columns: &[&'static str],
+    row: impl Fn(T) -> Vec<Value>,
+) -> Table
+where
+    T: IntoEnumIterator + EnumMessage + fmt::Display,
+{
+    let rows = T::iter()
+        .map(|value| {
+            let name = value.to_string();
+            let aliases = value
+                .get_serializations()
+                .iter()
+                .filter(|alias| **alias != name)
+                .collect::<Vec<_>>();
+            let mut values = vec![Value::Text(name.clone()), json!(aliases).into()];
+            
+            values.extend(row(value));
+            values
+        })
+        .collect();
+    let mut all_columns = vec!["name", "aliases"];
+    
+    all_columns.extend(columns);
+    
+    Table {
+        name,
+        columns: all_columns,
+        rows,
+    }
+}
+
+/// Same as [`enum_table`], with the integer representation of the variant as the first column.
+fn repr_table<T>(
+    name: &'static str,
+    columns: &[&'static str],
+    row: impl Fn(T) -> Vec<Value>,
+) -> Table
+where
+    T: IntoEnumIterator + EnumMessage + fmt::Display + Copy + Into<u32>,
+{
+    let mut table = enum_table(name, columns, row);
+    
+    table.columns.insert(0, "id");
+    
+    for (value, row) in T::iter().zip(&mut table.rows) {
+        row.insert(0, value.into().into());
+    }
+    
+    table
+}
+
+/// Formats a color as a hexadecimal color string in the format "#FFFFFF".
+fn color(color: u32) -> Value {
+    Value::Text(format!("#{color:06X}"))
+}
+
+/// Converts a list of values into a JSON list of their names.
+fn names<T: fmt::Display>(values: &[T]) -> Value {
+    Value::Json(values.iter().map(|value| json!(value.to_string())).collect())
+}
+
+/// Quotes a string as an SQL string literal.
+fn quote_sql(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    
+    #[test]
+    fn tables_are_consistent() {
+        let tables = tables();
+        let names = tables.iter().map(|table| table.name).collect::<HashSet<_>>();
+        
+        assert_eq!(names.len(), tables.len());
+        
+        for table in &tables {
+            assert!(!table.rows.is_empty(), "{} is empty", table.name);
+            assert!(
+                table.rows.iter().all(|row| row.len() == table.columns.len()),
+                "{} has rows with the wrong number of values",
+                table.name,
+            );
+        }
+    }
+    
+    #[test]
+    fn includes_aliases() {
+        let table = table("kill_eater_score_types").unwrap();
+        let ubers = &table.rows[1];
+        
+        assert_eq!(ubers[1], Value::Text("Übers".into()));
+        assert_eq!(ubers[2], Value::Json(json!(["Ubers"])));
+    }
+    
+    #[test]
+    fn renders_csv() {
+        let table = Table {
+            name: "test",
+            columns: vec!["id", "name", "levels"],
+            rows: vec![
+                vec![1u32.into(), "Hale's Own".into(), json!([1, 2]).into()],
+                vec![2u32.into(), "Say \"Hi\"".into(), Value::Null],
+            ],
+        };
+        
+        assert_eq!(
+            table.to_csv(),
+            "id,name,levels\n1,Hale's Own,\"[1,2]\"\n2,\"Say \"\"Hi\"\"\",\n",
+        );
+    }
+    
+    #[test]
+    fn renders_sql() {
+        let table = Table {
+            name: "test",
+            columns: vec!["id", "name", "is_weapon", "float_value"],
+            rows: vec![
+                vec![1u32.into(), "Hale's Own".into(), true.into(), 0.2f32.into()],
+                vec![2u32.into(), Value::Null, false.into(), 1.0f32.into()],
+            ],
+        };
+        
+        assert_eq!(table.to_sql(), "INSERT INTO test (id, name, is_weapon, float_value) VALUES
+    (1, 'Hale''s Own', TRUE, 0.2),
+    (2, NULL, FALSE, 1);
+");
+    }
+    
+    #[test]
+    fn renders_json() {
+        let table = table("wears").unwrap();
+        
+        assert_eq!(table.to_json()[0], json!({
+            "id": 1,
+            "name": "Factory New",
+            "aliases": [],
+            "float_value": 0.2,
+            "attribute_defindex": 725,
+        }));
+    }
+}
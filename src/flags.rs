@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use strum::{Display, EnumCount, EnumIter};
+use strum::{Display, EnumCount, EnumIter, EnumMessage};
 
 /// Flags.
 #[derive(
@@ -14,12 +14,16 @@ use strum::{Display, EnumCount, EnumIter};
     PartialOrd,
     Display,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
     Clone,
     Copy,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[allow(missing_docs)]
 pub enum Flags {
@@ -1,7 +1,7 @@
 use crate::{Rarity, Colored};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Grade. The repr values for each variant aren't associated with anything from the schema. Use
 /// [`Rarity`] if you need internal values. Instead, [`Grade`] is used for display purposes and each
@@ -33,10 +33,14 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Text))]
 #[repr(u32)]
 #[allow(missing_docs)]
 pub enum Grade {
@@ -48,6 +52,26 @@ pub enum Grade {
     Elite = 6,
 }
 
+impl Grade {
+    /// Gets the next highest grade. Returns `None` for [`Grade::Elite`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::Grade;
+    /// 
+    /// assert_eq!(Grade::Civilian.next(), Some(Grade::Freelance));
+    /// assert_eq!(Grade::Elite.next(), None);
+    /// ```
+    pub fn next(&self) -> Option<Self> {
+        Self::try_from(*self as u32 + 1).ok()
+    }
+    
+    /// Gets the next lowest grade. Returns `None` for [`Grade::Civilian`].
+    pub fn previous(&self) -> Option<Self> {
+        Self::try_from((*self as u32).checked_sub(1)?).ok()
+    }
+}
+
 impl Colored for Grade {
     /// Gets the related color of this grade as a hexadecimal color.
     fn color(&self) -> u32 {
@@ -0,0 +1,208 @@
+//! [`async_graphql`] implementations for types which cannot derive them.
+//! 
+//! Enums derive [`async_graphql::Enum`], using the names of their variants in
+//! SCREAMING_SNAKE_CASE, e.g. `SELF_MADE` for [`crate::Quality::SelfMade`]. Attribute sets are
+//! lists of their items and [`ItemAttribute`] is a scalar in its serialized form.
+
+use crate::{
+    AttributeSet,
+    ItemAttribute,
+    Spell,
+    SpellSet,
+    StrangePart,
+    StrangePartSet,
+};
+use std::borrow::Cow;
+use async_graphql::parser::types::Field;
+use async_graphql::registry::Registry;
+use async_graphql::resolver_utils::resolve_list;
+use async_graphql::{
+    ContextSelectionSet,
+    InputType,
+    InputValueError,
+    InputValueResult,
+    OutputType,
+    Positioned,
+    ServerResult,
+    Value,
+};
+
+/// Implements [`InputType`] and [`OutputType`] for attribute sets, which are lists of their
+/// items. Parsing fails if the list contains duplicates or has too many items.
+macro_rules! impl_graphql_attribute_set {
+    ($($t:ident => $item:ident),* $(,)?) => {
+        $(
+            impl InputType for $t {
+                type RawValueType = Self;
+                
+                fn type_name() -> Cow<'static, str> {
+                    <Vec<$item> as InputType>::type_name()
+                }
+                
+                fn qualified_type_name() -> String {
+                    <Vec<$item> as InputType>::qualified_type_name()
+                }
+                
+                fn create_type_info(registry: &mut Registry) -> String {
+                    <Vec<$item> as InputType>::create_type_info(registry)
+                }
+                
+                fn parse(value: Option<Value>) -> InputValueResult<Self> {
+                    let items = <Vec<$item> as InputType>::parse(value)
+                        .map_err(InputValueError::propagate)?;
+                    let mut set = Self::default();
+                    
+                    for item in items {
+                        set.try_insert(item).map_err(InputValueError::custom)?;
+                    }
+                    
+                    Ok(set)
+                }
+                
+                fn to_value(&self) -> Value {
+                    Value::List(self.into_iter().map(|item| InputType::to_value(&item)).collect())
+                }
+                
+                fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+                    Some(self)
+                }
+            }
+            
+            impl OutputType for $t {
+                fn type_name() -> Cow<'static, str> {
+                    <Vec<$item> as OutputType>::type_name()
+                }
+                
+                fn qualified_type_name() -> String {
+                    <Vec<$item> as OutputType>::qualified_type_name()
+                }
+                
+                fn create_type_info(registry: &mut Registry) -> String {
+                    <Vec<$item> as OutputType>::create_type_info(registry)
+                }
+                
+                async fn resolve(
+                    &self,
+                    ctx: &ContextSelectionSet<'_>,
+                    field: &Positioned<Field>,
+                ) -> ServerResult<Value> {
+                    resolve_list(ctx, field, self, Some(self.len())).await
+                }
+            }
+        )*
+    };
+}
+
+impl_graphql_attribute_set!(
+    SpellSet => Spell,
+    StrangePartSet => StrangePart,
+);
+
+async_graphql::scalar!(
+    ItemAttribute,
+    "ItemAttribute",
+    "An item attribute with a defindex, value, and float value."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Quality, Sheen};
+    use async_graphql::{EmptyMutation, EmptySubscription, EnumType, Object, Schema};
+    use std::collections::HashSet;
+    
+    struct Query;
+    
+    #[Object]
+    impl Query {
+        async fn quality(&self, quality: Quality) -> Quality {
+            quality
+        }
+        
+        async fn sheen(&self) -> Sheen {
+            Sheen::DeadlyDaffodil
+        }
+        
+        async fn spells(&self, spells: SpellSet) -> SpellSet {
+            spells
+        }
+        
+        async fn strange_parts(&self) -> StrangePartSet {
+            StrangePartSet::from([Some(StrangePart::CriticalKills), None, None])
+        }
+        
+        async fn attribute(&self, attribute: ItemAttribute) -> ItemAttribute {
+            attribute
+        }
+    }
+    
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+    }
+    
+    #[test]
+    fn enum_names_are_graphql_safe() {
+        let sdl = schema().sdl();
+        
+        assert!(sdl.contains("enum Quality"));
+        assert!(sdl.contains("\tCOLLECTORS\n"));
+        assert!(sdl.contains("\tSELF_MADE\n"));
+        assert!(sdl.contains("\tDEADLY_DAFFODIL\n"));
+        assert!(sdl.contains("spells(spells: [Spell!]!): [Spell!]!"));
+        assert!(sdl.contains("scalar ItemAttribute"));
+    }
+    
+    fn assert_valid_names<T: EnumType + OutputType>() {
+        let names = T::items().iter().map(|item| item.name).collect::<HashSet<_>>();
+        
+        assert_eq!(names.len(), T::items().len(), "{} has duplicate names", <T as OutputType>::type_name());
+        
+        for name in names {
+            assert!(name.starts_with(|c: char| c.is_ascii_uppercase() || c == '_'), "{name}");
+            assert!(name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'), "{name}");
+        }
+    }
+    
+    #[test]
+    fn every_enum_has_valid_names() {
+        macro_rules! assert_all_valid_names {
+            ($($t:ident => $name:literal),* $(,)?) => {
+                $(assert_valid_names::<crate::$t>();)*
+            };
+        }
+        
+        for_each_enum!(assert_all_valid_names);
+    }
+    
+    #[tokio::test]
+    async fn resolves_query() {
+        let response = schema()
+            .execute(r#"{
+                quality(quality: SELF_MADE)
+                sheen
+                spells(spells: [HALLOWEEN_FIRE, EXORCISM])
+                strangeParts
+                attribute(attribute: { defindex: 1004, value: 2, float_value: 2 })
+            }"#)
+            .await;
+        
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        
+        let data = response.data.into_json().unwrap();
+        
+        assert_eq!(data["quality"], "SELF_MADE");
+        assert_eq!(data["sheen"], "DEADLY_DAFFODIL");
+        assert_eq!(data["spells"], serde_json::json!(["HALLOWEEN_FIRE", "EXORCISM"]));
+        assert_eq!(data["strangeParts"], serde_json::json!(["CRITICAL_KILLS"]));
+        assert_eq!(data["attribute"]["defindex"], 1004);
+    }
+    
+    #[tokio::test]
+    async fn rejects_duplicate_set_items() {
+        let response = schema()
+            .execute("{ spells(spells: [EXORCISM, EXORCISM]) }")
+            .await;
+        
+        assert_eq!(response.errors.len(), 1);
+    }
+}
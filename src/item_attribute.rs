@@ -2,10 +2,13 @@
 
 use crate::AttributeValue;
 use crate::serialize::option_float_as_integers_when_whole;
+use std::ops::{Deref, DerefMut};
 use serde::{Deserialize, Serialize};
 
 /// Container type for item attributes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ItemAttribute {
     /// The defindex of this attribute.
     pub defindex: u32,
@@ -17,3 +20,177 @@ pub struct ItemAttribute {
     #[serde(serialize_with = "option_float_as_integers_when_whole")]
     pub float_value: Option<f32>,
 }
+
+impl ItemAttribute {
+    /// Gets the value of an attribute which is stored as an integer. The float value of these
+    /// attributes is the integer's bits reinterpreted as a float.
+    pub(crate) fn integer_value(&self) -> Option<u32> {
+        match self.value {
+            AttributeValue::Integer(value) => Some(value),
+            _ => self.float_value.map(f32::to_bits),
+        }
+    }
+}
+
+/// A list of item attributes.
+/// 
+/// Serializes the same as a `Vec<ItemAttribute>`. With the `sqlx-postgres-0_8` feature, the list
+/// is stored as a single `jsonb` array, whereas a `Vec<ItemAttribute>` is stored as `jsonb[]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(transparent)]
+pub struct ItemAttributes(pub Vec<ItemAttribute>);
+
+impl Deref for ItemAttributes {
+    type Target = Vec<ItemAttribute>;
+    
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ItemAttributes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<ItemAttribute>> for ItemAttributes {
+    fn from(attributes: Vec<ItemAttribute>) -> Self {
+        Self(attributes)
+    }
+}
+
+impl From<ItemAttributes> for Vec<ItemAttribute> {
+    fn from(attributes: ItemAttributes) -> Self {
+        attributes.0
+    }
+}
+
+impl FromIterator<ItemAttribute> for ItemAttributes {
+    fn from_iter<T: IntoIterator<Item = ItemAttribute>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for ItemAttributes {
+    type Item = ItemAttribute;
+    type IntoIter = std::vec::IntoIter<ItemAttribute>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ItemAttributes {
+    type Item = &'a ItemAttribute;
+    type IntoIter = std::slice::Iter<'a, ItemAttribute>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Stored as `jsonb`. A `Vec<ItemAttribute>` is stored as `jsonb[]`; use [`ItemAttributes`] to
+/// store the list as a single `jsonb` value.
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Type<::sqlx::Postgres> for ItemAttribute {
+    fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+        <::sqlx::types::Json<Self> as ::sqlx::Type<::sqlx::Postgres>>::type_info()
+    }
+    
+    fn compatible(ty: &::sqlx::postgres::PgTypeInfo) -> bool {
+        <::sqlx::types::Json<Self> as ::sqlx::Type<::sqlx::Postgres>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<'r> ::sqlx::Decode<'r, ::sqlx::Postgres> for ItemAttribute {
+    fn decode(
+        value: <::sqlx::Postgres as ::sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+        Ok(<::sqlx::types::Json<Self> as ::sqlx::Decode<'r, ::sqlx::Postgres>>::decode(value)?.0)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Encode<'_, ::sqlx::Postgres> for ItemAttribute {
+    fn encode_by_ref(&self, buf: &mut ::sqlx::postgres::PgArgumentBuffer) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        <::sqlx::types::Json<&Self> as ::sqlx::Encode<'_, ::sqlx::Postgres>>::encode(::sqlx::types::Json(self), buf)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::postgres::PgHasArrayType for ItemAttribute {
+    fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+        <::sqlx::types::Json<Self> as ::sqlx::postgres::PgHasArrayType>::array_type_info()
+    }
+    
+    fn array_compatible(ty: &::sqlx::postgres::PgTypeInfo) -> bool {
+        <::sqlx::types::Json<Self> as ::sqlx::postgres::PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+/// Stored as a single `jsonb` array.
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Type<::sqlx::Postgres> for ItemAttributes {
+    fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+        <::sqlx::types::Json<Vec<ItemAttribute>> as ::sqlx::Type<::sqlx::Postgres>>::type_info()
+    }
+    
+    fn compatible(ty: &::sqlx::postgres::PgTypeInfo) -> bool {
+        <::sqlx::types::Json<Vec<ItemAttribute>> as ::sqlx::Type<::sqlx::Postgres>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<'r> ::sqlx::Decode<'r, ::sqlx::Postgres> for ItemAttributes {
+    fn decode(
+        value: <::sqlx::Postgres as ::sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+        Ok(Self(<::sqlx::types::Json<Vec<ItemAttribute>> as ::sqlx::Decode<'r, ::sqlx::Postgres>>::decode(value)?.0))
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Encode<'_, ::sqlx::Postgres> for ItemAttributes {
+    fn encode_by_ref(&self, buf: &mut ::sqlx::postgres::PgArgumentBuffer) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        <::sqlx::types::Json<&Vec<ItemAttribute>> as ::sqlx::Encode<'_, ::sqlx::Postgres>>::encode(::sqlx::types::Json(&self.0), buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn serializes_item_attributes_as_a_list() {
+        let json = r#"[{"defindex":142,"value":1315860,"float_value":1315860}]"#;
+        let attributes: ItemAttributes = serde_json::from_str(json).unwrap();
+        
+        assert_eq!(attributes.len(), 1);
+        assert_eq!(attributes[0].defindex, 142);
+        assert_eq!(serde_json::to_string(&attributes).unwrap(), json);
+    }
+    
+    #[cfg(feature = "sqlx-postgres-0_8")]
+    #[test]
+    fn encodes_item_attributes_as_jsonb() {
+        use sqlx::{Encode, Type, TypeInfo};
+        
+        let attributes = ItemAttributes(vec![ItemAttribute {
+            defindex: 142,
+            value: AttributeValue::Integer(1315860),
+            float_value: Some(1315860.0),
+        }]);
+        let mut buf = sqlx::postgres::PgArgumentBuffer::default();
+        
+        assert!(matches!(attributes.encode_by_ref(&mut buf).unwrap(), sqlx::encode::IsNull::No));
+        assert_eq!(<ItemAttributes as Type<sqlx::Postgres>>::type_info().name(), "JSONB");
+        assert_eq!(<Vec<ItemAttribute> as Type<sqlx::Postgres>>::type_info().name(), "JSONB[]");
+        // The jsonb format version, followed by a single JSON array.
+        assert_eq!(buf[0], 1);
+        assert_eq!(buf[1], b'[');
+    }
+}
@@ -1,7 +1,7 @@
 use crate::KillEaterScoreType;
 use std::fmt;
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Item level.
 #[derive(
@@ -18,11 +18,16 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Serialize,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Text))]
 #[non_exhaustive]
 #[allow(missing_docs)]
 pub enum ItemLevel {
+    // codegen:start variants
     #[strum(serialize = "KillEaterRank")]
     #[serde(rename = "KillEaterRank")]
     KillEaterRank,
@@ -65,6 +70,7 @@ pub enum ItemLevel {
     #[strum(serialize = "KillEater_BackstabsAbsorbed")]
     #[serde(rename = "KillEater_BackstabsAbsorbed")]
     KillEaterBackstabsAbsorbed,
+    // codegen:end variants
 }
 
 impl ItemLevel {
@@ -87,6 +93,7 @@ impl ItemLevel {
     /// Gets the levels for this [`ItemLevel`].
     pub fn levels(&self) -> &'static [Level] {
         match self {
+            // codegen:start levels
             Self::KillEaterRank => &[
                 Level { level: 0, required_score: 10, name: "Strange" },
                 Level { level: 1, required_score: 25, name: "Unremarkable" },
@@ -380,6 +387,7 @@ impl ItemLevel {
                 Level { level: 19, required_score: 850, name: "Australian" },
                 Level { level: 20, required_score: 1000, name: "Hale's Own" },
             ],
+            // codegen:end levels
         }
     }
 }
@@ -387,6 +395,7 @@ impl ItemLevel {
 impl From<KillEaterScoreType> for ItemLevel {
     fn from(score_type: KillEaterScoreType) -> Self {
         match score_type {
+            // codegen:start score_type_levels
             KillEaterScoreType::Kills |
             KillEaterScoreType::Ubers |
             KillEaterScoreType::KillAssists |
@@ -481,6 +490,7 @@ impl From<KillEaterScoreType> for ItemLevel {
             KillEaterScoreType::ContractPoints => Self::KillEaterOperationContractRank,
             KillEaterScoreType::SoulsCollected => Self::KillEaterHalloweenSoulsRank,
             KillEaterScoreType::ContractPointsEarned => Self::KillEaterContractPointsEarnedRank,
+            // codegen:end score_type_levels
         }
     }
 }
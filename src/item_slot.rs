@@ -1,6 +1,6 @@
 use crate::StockWeapon;
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Item slot.
 #[derive(
@@ -17,8 +17,12 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Serialize,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Text))]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -42,6 +46,30 @@ pub enum ItemSlot {
 }
 
 impl ItemSlot {
+    /// Item slots for weapons.
+    pub const WEAPONS: &'static [ItemSlot] = &[
+        ItemSlot::Primary,
+        ItemSlot::Secondary,
+        ItemSlot::Melee,
+        ItemSlot::PDA,
+        ItemSlot::PDA2,
+        ItemSlot::Building,
+    ];
+    /// Item slots for cosmetics.
+    pub const COSMETICS: &'static [ItemSlot] = &[
+        ItemSlot::Misc,
+    ];
+    
+    /// Checks if this item slot is for weapons.
+    pub fn is_weapon(&self) -> bool {
+        Self::WEAPONS.contains(self)
+    }
+    
+    /// Checks if this item slot is for cosmetics.
+    pub fn is_cosmetic(&self) -> bool {
+        Self::COSMETICS.contains(self)
+    }
+    
     /// Gets the stock weapons available for this item slot.
     pub fn stock_weapons(&self) -> &'static [StockWeapon] {
         match self {
@@ -0,0 +1,120 @@
+//! [`JsonSchema`] implementations matching the serialized form of each type.
+
+use crate::{AttributeSet, ItemAttribute, SpellSet, StrangePartSet};
+use crate::serialize;
+use std::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+/// Implements [`JsonSchema`] for enums. The allowed values are taken from serializing each
+/// variant, so the schema is an integer for enums serialized by their repr and a string otherwise.
+macro_rules! impl_json_schema_enum {
+    ($($t:ident => $name:literal),* $(,)?) => {
+        $(
+            impl JsonSchema for crate::$t {
+                fn schema_name() -> Cow<'static, str> {
+                    stringify!($t).into()
+                }
+                
+                fn schema_id() -> Cow<'static, str> {
+                    concat!("tf2_enum::", stringify!($t)).into()
+                }
+                
+                fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+                    enum_schema::<crate::$t>()
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`JsonSchema`] for attribute sets, which are serialized as arrays of attributes.
+macro_rules! impl_json_schema_attribute_set {
+    ($($t:ident),* $(,)?) => {
+        $(
+            impl JsonSchema for $t {
+                fn schema_name() -> Cow<'static, str> {
+                    stringify!($t).into()
+                }
+                
+                fn schema_id() -> Cow<'static, str> {
+                    concat!("tf2_enum::", stringify!($t)).into()
+                }
+                
+                fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+                    json_schema!({
+                        "type": "array",
+                        "items": generator.subschema_for::<ItemAttribute>(),
+                        "maxItems": <$t as AttributeSet>::MAX_COUNT,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+for_each_enum!(impl_json_schema_enum);
+
+impl_json_schema_attribute_set!(SpellSet, StrangePartSet);
+
+/// Builds the schema for an enum from the serialized values of its variants.
+fn enum_schema<T: IntoEnumIterator + Serialize>() -> Schema {
+    let values = serialize::enum_values::<T>();
+    let schema_type = if values.iter().all(serde_json::Value::is_u64) {
+        "integer"
+    } else {
+        "string"
+    };
+    
+    json_schema!({
+        "type": schema_type,
+        "enum": values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Class, ItemSlot, Quality, Spell, StrangePart};
+    use schemars::schema_for;
+    use serde_json::json;
+    
+    #[test]
+    fn repr_enums_are_integers() {
+        let schema = schema_for!(Quality);
+        
+        assert_eq!(schema.get("type"), Some(&json!("integer")));
+        assert!(schema.get("enum").unwrap().as_array().unwrap().contains(&json!(5)));
+    }
+    
+    #[test]
+    fn string_enums_are_strings() {
+        let class = schema_for!(Class);
+        let spell = schema_for!(Spell);
+        let item_slot = schema_for!(ItemSlot);
+        
+        assert_eq!(class.get("type"), Some(&json!("string")));
+        assert!(class.get("enum").unwrap().as_array().unwrap().contains(&json!("Scout")));
+        assert!(spell.get("enum").unwrap().as_array().unwrap().contains(&json!("Halloween Fire")));
+        assert!(item_slot.get("enum").unwrap().as_array().unwrap().contains(&json!("pda2")));
+    }
+    
+    #[test]
+    fn enums_include_every_value() {
+        let schema = schema_for!(StrangePart);
+        let values = schema.get("enum").unwrap().as_array().unwrap();
+        
+        assert_eq!(values.len(), StrangePart::iter().count());
+    }
+    
+    #[test]
+    fn sets_are_attribute_arrays() {
+        let schema = schema_for!(SpellSet);
+        
+        assert_eq!(schema.get("type"), Some(&json!("array")));
+        assert_eq!(schema.get("items"), Some(&json!({ "$ref": "#/$defs/ItemAttribute" })));
+        assert_eq!(schema.get("maxItems"), Some(&json!(SpellSet::MAX_COUNT)));
+        assert!(schema.get("$defs").unwrap().get("ItemAttribute").is_some());
+    }
+}
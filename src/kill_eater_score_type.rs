@@ -9,7 +9,7 @@ use crate::{
 use crate::error::TryFromPrimitiveError;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter};
+use strum::{Display, EnumCount, EnumIter, EnumMessage};
 
 /// Kill eater score type. Conversion from strings is not supported due to multiple variants
 /// having the same string representation. They can still be formatted into strings.
@@ -24,16 +24,21 @@ use strum::{Display, EnumCount, EnumIter};
     PartialOrd,
     Display,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
     Clone,
     Copy,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[non_exhaustive]
 #[allow(missing_docs)]
 pub enum KillEaterScoreType {
+    // codegen:start variants
     /// "Kills" used for most Strange weapons.
     #[strum(serialize = "Kills")]
     Kills = 0,
@@ -226,6 +231,7 @@ pub enum KillEaterScoreType {
     ContractPointsEarned = 110,
     #[strum(serialize = "Contract Points Contributed To Friends")]
     ContractPointsContributedToFriends = 111,
+    // codegen:end variants
 }
 
 impl KillEaterScoreType {
@@ -8,7 +8,7 @@ use crate::{
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Killstreak tier.
 #[derive(
@@ -25,10 +25,14 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[allow(missing_docs)]
 pub enum KillstreakTier {
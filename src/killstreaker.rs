@@ -8,9 +8,13 @@ use crate::{
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Killstreaker.
+/// 
+/// Killstreakers are particle systems with several colors each. The game data has no single RGB
+/// value for them, so unlike [`Sheen`][crate::Sheen] they don't implement
+/// [`Colored`][crate::Colored] or [`MaybeColored`][crate::MaybeColored].
 #[derive(
     Debug,
     Clone,
@@ -25,10 +29,14 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[strum(serialize_all = "title_case")]
 #[allow(missing_docs)]
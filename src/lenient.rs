@@ -0,0 +1,100 @@
+//! Lenient deserialization of attribute sets.
+
+use crate::ItemAttribute;
+use crate::error::AttributeValueError;
+use std::ops::{Deref, DerefMut};
+use serde::{Serialize, Serializer};
+
+/// Deserializes an attribute set without failing on attributes which cannot be converted.
+/// 
+/// Deserializing a [`SpellSet`][`crate::SpellSet`] or [`StrangePartSet`][`crate::StrangePartSet`]
+/// fails on the first attribute with a missing or unknown `float_value`. Wrapping the set in
+/// [`Lenient`] skips these attributes instead and collects them into
+/// [`skipped`][`Lenient::skipped`]. Serializing a [`Lenient`] serializes the set.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{AttributeSet, Lenient, StrangePart, StrangePartSet};
+/// use tf2_enum::error::AttributeValueError;
+/// 
+/// let json = r#"[
+///     { "defindex": 380, "float_value": 77 },
+///     { "defindex": 382, "float_value": 9999 },
+///     { "defindex": 384 }
+/// ]"#;
+/// 
+/// assert!(serde_json::from_str::<StrangePartSet>(json).is_err());
+/// 
+/// let strange_parts: Lenient<StrangePartSet> = serde_json::from_str(json).unwrap();
+/// 
+/// assert_eq!(*strange_parts, StrangePartSet::from([Some(StrangePart::TauntKills), None, None]));
+/// assert_eq!(strange_parts.skipped.len(), 2);
+/// assert_eq!(strange_parts.skipped[0].attribute.defindex, 382);
+/// assert_eq!(strange_parts.skipped[0].error, AttributeValueError::UnknownValue);
+/// assert_eq!(strange_parts.skipped[1].error, AttributeValueError::MissingFloatValue);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Lenient<T> {
+    /// The deserialized value.
+    pub value: T,
+    /// The attributes which were skipped.
+    pub skipped: Vec<SkippedAttribute>,
+}
+
+impl<T> Lenient<T> {
+    /// Creates a value with no skipped attributes.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            skipped: Vec::new(),
+        }
+    }
+    
+    /// Consumes this, returning the deserialized value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+    
+    /// Returns `true` if no attributes were skipped.
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
+impl<T> From<T> for Lenient<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Deref for Lenient<T> {
+    type Target = T;
+    
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Lenient<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: Serialize> Serialize for Lenient<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+/// An attribute which was skipped during lenient deserialization.
+#[derive(Debug, Clone)]
+pub struct SkippedAttribute {
+    /// The attribute.
+    pub attribute: ItemAttribute,
+    /// The reason the attribute was skipped.
+    pub error: AttributeValueError,
+}
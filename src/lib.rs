@@ -22,22 +22,98 @@
 //! }
 //! ```
 //! 
-//! If you are using [sqlx](https://crates.io/crates/sqlx) (v0.8) with PostgreSQL, you can enable
-//! the `sqlx-postgres-0_8` feature. This adds the appropriate bindings for Postgres databases for
-//! enums.
+//! If you are using [sqlx](https://crates.io/crates/sqlx) (v0.8), you can enable the
+//! `sqlx-postgres-0_8`, `sqlx-sqlite-0_8`, or `sqlx-mysql-0_8` features. These add the appropriate
+//! bindings for enums to each database. Enums are stored the same way on every database, either as
+//! text or as integers. On Postgres, enums can also be stored as native `ENUM` types using the
+//! [`pg_enum`] module.
+//! 
+//! If you are using [diesel](https://crates.io/crates/diesel) (v2), the `diesel-2-postgres` and
+//! `diesel-2-sqlite` features add the same bindings for Postgres and SQLite, with enums usable as
+//! `Text` or `Integer` columns.
+//! 
+//! The `schema` feature adds the [`schema`] module for loading local dumps of the item schema,
+//! which includes the frequently updated values not covered by this crate, and the [`vdf`] module
+//! for parsing Valve's KeyValues format.
+//! 
+//! The `schemars` feature implements [`JsonSchema`](https://docs.rs/schemars) for each enum,
+//! [`ItemAttribute`], [`AttributeValue`], [`SpellSet`], and [`StrangePartSet`], matching how each
+//! type is serialized. The `utoipa` feature implements [`ToSchema`](https://docs.rs/utoipa) for
+//! the same types for generating OpenAPI documents.
+//! 
+//! The `async-graphql` feature exposes each enum as a GraphQL enum with its variant names in
+//! SCREAMING_SNAKE_CASE, e.g. `SELF_MADE`. [`SpellSet`] and [`StrangePartSet`] are lists of their
+//! items and [`ItemAttribute`] is a scalar in its serialized form.
+//! 
+//! The `export` feature adds the [`export`] module for exporting the values of each enum as lookup
+//! tables in CSV, JSON, or SQL, and the [`typescript`] module for generating TypeScript definitions
+//! which match how each enum is serialized.
+//! 
+//! Unusual effects are loaded at runtime into a [`ParticleEffectRegistry`]. A snapshot of effects
+//! is available with the `particle-effects-snapshot` feature.
 
 #![warn(missing_docs)]
 
+/// Invokes `$callback!` with every enum in this crate along with its name in snake_case, e.g.
+/// `Capability => "capability"`. Modules which implement something for each enum are driven from
+/// this list so that a new enum only needs to be added here.
+macro_rules! for_each_enum {
+    ($callback:ident) => {
+        $callback! {
+            Capability => "capability",
+            Class => "class",
+            CraftClass => "craft_class",
+            CraftMaterialType => "craft_material_type",
+            DescriptionFormat => "description_format",
+            DropType => "drop_type",
+            EffectType => "effect_type",
+            Flags => "flags",
+            FootprintsSpell => "footprints_spell",
+            GCItemSort => "gc_item_sort",
+            Grade => "grade",
+            HolidayRestriction => "holiday_restriction",
+            ItemLevel => "item_level",
+            ItemSlot => "item_slot",
+            KillEaterScoreType => "kill_eater_score_type",
+            KillstreakTier => "killstreak_tier",
+            Killstreaker => "killstreaker",
+            Origin => "origin",
+            Paint => "paint",
+            PaintSpell => "paint_spell",
+            Quality => "quality",
+            Rarity => "rarity",
+            Sheen => "sheen",
+            Spell => "spell",
+            StockWeapon => "stock_weapon",
+            StrangePart => "strange_part",
+            Team => "team",
+            Wear => "wear",
+        }
+    };
+}
+
+pub mod color;
 pub mod econ_attributes;
 pub mod error;
+#[cfg(feature = "export")]
+pub mod export;
+pub mod pg_enum;
 pub mod prelude;
+#[cfg(feature = "export")]
+pub mod typescript;
+#[cfg(feature = "schema")]
+pub mod schema;
+#[cfg(feature = "schema")]
+pub mod vdf;
 
 mod attribute_def;
 mod attribute_value;
 mod capability;
 mod class;
+mod collection;
 mod craft_class;
 mod craft_material_type;
+mod decorated_weapon;
 mod description_format;
 mod drop_type;
 mod effect_type;
@@ -51,10 +127,16 @@ mod item_slot;
 mod kill_eater_score_type;
 mod killstreak_tier;
 mod killstreaker;
+mod lenient;
+mod maybe_known;
 mod origin;
 mod paint;
+mod paint_kit;
+mod particle_effect;
 mod quality;
 mod rarity;
+mod registry;
+mod restoration;
 mod sheen;
 mod serialize;
 mod spell;
@@ -62,11 +144,12 @@ mod spell_set;
 mod stock_weapon;
 mod strange_part;
 mod strange_part_set;
+mod team;
 mod traits;
 mod wear;
 
 // Traits and utility re-exports
-pub use strum::{EnumCount, IntoEnumIterator};
+pub use strum::{EnumCount, EnumMessage, IntoEnumIterator};
 pub use num_enum::{IntoPrimitive, TryFromPrimitive};
 pub use traits::{
     Attribute,
@@ -74,7 +157,10 @@ pub use traits::{
     AttributeSet,
     TryFromIntAttributeValue,
     Colored,
-    HasItemDefindex
+    TeamColored,
+    MaybeColored,
+    HasItemDefindex,
+    RegistryEntry,
 };
 
 // Enum re-exports
@@ -82,8 +168,11 @@ pub use attribute_def::AttributeDef;
 pub use attribute_value::AttributeValue;
 pub use capability::Capability;
 pub use class::Class;
+pub use collection::{Collection, TradeUpOutcome};
+pub use color::ColorMatch;
 pub use craft_class::CraftClass;
 pub use craft_material_type::CraftMaterialType;
+pub use decorated_weapon::DecoratedWeapon;
 pub use description_format::DescriptionFormat;
 pub use drop_type::DropType;
 pub use effect_type::EffectType;
@@ -91,79 +180,115 @@ pub use flags::Flags;
 pub use gc_item_sort::GCItemSort;
 pub use grade::Grade;
 pub use holiday_restriction::HolidayRestriction;
-pub use item_attribute::ItemAttribute;
+pub use item_attribute::{ItemAttribute, ItemAttributes};
 pub use item_level::{ItemLevel, Level};
 pub use item_slot::ItemSlot;
 pub use kill_eater_score_type::KillEaterScoreType;
 pub use killstreak_tier::KillstreakTier;
 pub use killstreaker::Killstreaker;
+pub use lenient::{Lenient, SkippedAttribute};
+pub use maybe_known::MaybeKnown;
 pub use origin::Origin;
 pub use paint::Paint;
+pub use paint_kit::{PaintKit, PaintKitRegistry, PaintKitSeed};
+pub use particle_effect::{ParticleEffect, ParticleEffectRegistry};
 pub use quality::Quality;
 pub use rarity::Rarity;
+pub use registry::Registry;
+pub use restoration::{Customization, Restoration};
 pub use sheen::Sheen;
 pub use spell::{FootprintsSpell, PaintSpell, Spell};
 pub use spell_set::{SpellSet, SpellSetIterator};
 pub use stock_weapon::StockWeapon;
 pub use strange_part::StrangePart;
 pub use strange_part_set::{StrangePartSet, StrangePartSetIterator};
+pub use team::Team;
 pub use wear::Wear;
 
-#[cfg(feature = "sqlx-postgres-0_8")]
+#[cfg(any(
+    feature = "sqlx-postgres-0_8",
+    feature = "sqlx-sqlite-0_8",
+    feature = "sqlx-mysql-0_8",
+))]
 mod sqlx_macros;
+#[cfg(feature = "diesel-2")]
+mod diesel_macros;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(feature = "async-graphql")]
+mod graphql;
 
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(Capability);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(Class);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(CraftClass);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(CraftMaterialType);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(DescriptionFormat);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(DropType);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(EffectType);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(Grade);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(HolidayRestriction);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(ItemLevel);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(ItemSlot);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(Spell);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_display_postgres!(StockWeapon);
+/// Implements the sqlx and diesel traits for each enabled database. Enums in `display` are stored
+/// as text and enums in `repr` are stored as integers.
+macro_rules! impl_database_enums {
+    (
+        display: [$($display:ty),* $(,)?],
+        repr: [$($repr:ty),* $(,)?] $(,)?
+    ) => {
+        $(
+            #[cfg(feature = "sqlx-postgres-0_8")]
+            impl_sqlx_enum_display_postgres!($display);
+            #[cfg(feature = "sqlx-sqlite-0_8")]
+            impl_sqlx_enum_display_sqlite!($display);
+            #[cfg(feature = "sqlx-mysql-0_8")]
+            impl_sqlx_enum_display_mysql!($display);
+            #[cfg(feature = "diesel-2")]
+            impl_diesel_enum_display!($display);
+            #[cfg(feature = "diesel-2-postgres")]
+            impl_diesel_enum_display_postgres!($display);
+            #[cfg(feature = "diesel-2-sqlite")]
+            impl_diesel_enum_display_sqlite!($display);
+        )*
+        $(
+            #[cfg(feature = "sqlx-postgres-0_8")]
+            impl_sqlx_enum_repr_postgres!($repr);
+            #[cfg(feature = "sqlx-sqlite-0_8")]
+            impl_sqlx_enum_repr_sqlite!($repr);
+            #[cfg(feature = "sqlx-mysql-0_8")]
+            impl_sqlx_enum_repr_mysql!($repr);
+            #[cfg(feature = "diesel-2")]
+            impl_diesel_enum_repr!($repr);
+            #[cfg(feature = "diesel-2-postgres")]
+            impl_diesel_enum_repr_postgres!($repr);
+            #[cfg(feature = "diesel-2-sqlite")]
+            impl_diesel_enum_repr_sqlite!($repr);
+        )*
+    };
+}
 
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Flags);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(FootprintsSpell);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(GCItemSort);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(KillEaterScoreType);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(KillstreakTier);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Killstreaker);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Origin);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Paint);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(PaintSpell);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Quality);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Rarity);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Sheen);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(StrangePart);
-#[cfg(feature = "sqlx-postgres-0_8")]
-impl_sqlx_enum_repr_postgres!(Wear);
+impl_database_enums! {
+    display: [
+        Capability,
+        Class,
+        CraftClass,
+        CraftMaterialType,
+        DescriptionFormat,
+        DropType,
+        EffectType,
+        Grade,
+        HolidayRestriction,
+        ItemLevel,
+        ItemSlot,
+        Spell,
+        StockWeapon,
+    ],
+    repr: [
+        Flags,
+        FootprintsSpell,
+        GCItemSort,
+        KillEaterScoreType,
+        KillstreakTier,
+        Killstreaker,
+        Origin,
+        Paint,
+        PaintSpell,
+        Quality,
+        Rarity,
+        Sheen,
+        StrangePart,
+        Team,
+        Wear,
+    ],
+}
@@ -0,0 +1,230 @@
+//! A wrapper for repr enums which keeps values that are not known to this crate.
+
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A value of a repr enum which may not be known to this crate.
+/// 
+/// Valve occasionally adds new values to the schema, such as a new [`Origin`][`crate::Origin`] or
+/// [`Quality`][`crate::Quality`]. Converting these into an enum fails, which aborts deserializing
+/// the entire structure containing them. Wrapping the field in [`MaybeKnown`] keeps the integer
+/// instead, so the value survives a round trip through serde, sqlx, and [`Display`][`fmt::Display`]
+/// and [`FromStr`].
+/// 
+/// Values are serialized as their integer representation, the same as the wrapped enum.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{MaybeKnown, Origin};
+/// 
+/// let origins: Vec<MaybeKnown<Origin>> = serde_json::from_str("[1, 9999]").unwrap();
+/// 
+/// assert_eq!(origins, vec![MaybeKnown::Known(Origin::Achievement), MaybeKnown::Unknown(9999)]);
+/// assert_eq!(origins[0].to_string(), "Achievement");
+/// assert_eq!(origins[1].to_string(), "9999");
+/// assert_eq!(serde_json::to_string(&origins).unwrap(), "[1,9999]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MaybeKnown<T> {
+    /// A value which is known to this crate.
+    Known(T),
+    /// A value which is not known to this crate.
+    Unknown(u32),
+}
+
+impl<T> MaybeKnown<T> {
+    /// Returns the known value, if any.
+    pub fn known(self) -> Option<T> {
+        match self {
+            Self::Known(value) => Some(value),
+            Self::Unknown(_) => None,
+        }
+    }
+    
+    /// Checks if the value is known.
+    pub fn is_known(&self) -> bool {
+        matches!(self, Self::Known(_))
+    }
+    
+    /// Checks if the value is unknown.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+    
+    /// Converts from `&MaybeKnown<T>` to `MaybeKnown<&T>`.
+    pub fn as_ref(&self) -> MaybeKnown<&T> {
+        match self {
+            Self::Known(value) => MaybeKnown::Known(value),
+            Self::Unknown(value) => MaybeKnown::Unknown(*value),
+        }
+    }
+}
+
+impl<T: TryFrom<u32>> MaybeKnown<T> {
+    /// Converts an integer into a value, which is [`MaybeKnown::Unknown`] if the integer does not
+    /// correspond to a value of `T`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{MaybeKnown, Quality};
+    /// 
+    /// assert_eq!(MaybeKnown::<Quality>::new(11), MaybeKnown::Known(Quality::Strange));
+    /// assert_eq!(MaybeKnown::<Quality>::new(1000), MaybeKnown::Unknown(1000));
+    /// ```
+    pub fn new(value: u32) -> Self {
+        T::try_from(value)
+            .map(Self::Known)
+            .unwrap_or(Self::Unknown(value))
+    }
+}
+
+impl<T: Copy + Into<u32>> MaybeKnown<T> {
+    /// Gets the integer representation of the value.
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::Known(value) => (*value).into(),
+            Self::Unknown(value) => *value,
+        }
+    }
+}
+
+impl<T> From<T> for MaybeKnown<T> {
+    fn from(value: T) -> Self {
+        Self::Known(value)
+    }
+}
+
+impl<T: Copy + Into<u32>> From<MaybeKnown<T>> for u32 {
+    fn from(value: MaybeKnown<T>) -> Self {
+        value.value()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MaybeKnown<T> {
+    /// Formats known values using their [`Display`][`fmt::Display`] implementation and unknown
+    /// values as their integer.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Known(value) => value.fmt(f),
+            Self::Unknown(value) => value.fmt(f),
+        }
+    }
+}
+
+impl<T: FromStr + TryFrom<u32>> FromStr for MaybeKnown<T> {
+    type Err = T::Err;
+    
+    /// Parses a name of `T`, or an integer as formatted for unknown values.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match T::from_str(s) {
+            Ok(value) => Ok(Self::Known(value)),
+            Err(error) => s.parse::<u32>().map(Self::new).map_err(|_| error),
+        }
+    }
+}
+
+impl<T: Copy + Into<u32>> Serialize for MaybeKnown<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(self.value())
+    }
+}
+
+impl<'de, T: TryFrom<u32>> Deserialize<'de> for MaybeKnown<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u32::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<T, DB: ::sqlx::Database> ::sqlx::Type<DB> for MaybeKnown<T>
+where
+    i32: ::sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i32 as ::sqlx::Type<DB>>::type_info()
+    }
+    
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i32 as ::sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r, T: TryFrom<u32>, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for MaybeKnown<T>
+where
+    i32: ::sqlx::Decode<'r, DB>,
+{
+    fn decode(
+        value: DB::ValueRef<'r>,
+    ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+        let v = <i32 as ::sqlx::Decode<'r, DB>>::decode(value)?;
+        
+        Ok(Self::new(v as u32))
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, T: Copy + Into<u32>, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for MaybeKnown<T>
+where
+    i32: ::sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let v = self.value() as i32;
+        <i32 as ::sqlx::Encode<'q, DB>>::encode(v, buf)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<T> ::sqlx::postgres::PgHasArrayType for MaybeKnown<T> {
+    fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+        <i32 as ::sqlx::postgres::PgHasArrayType>::array_type_info()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KillEaterScoreType, Killstreaker, Origin, Quality};
+    
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Item {
+        quality: MaybeKnown<Quality>,
+        origin: MaybeKnown<Origin>,
+    }
+    
+    #[test]
+    fn round_trips_unknown_values() {
+        let json = r#"{"quality":6,"origin":1000}"#;
+        let item: Item = serde_json::from_str(json).unwrap();
+        
+        assert_eq!(item.quality, MaybeKnown::Known(Quality::Unique));
+        assert_eq!(item.origin, MaybeKnown::Unknown(1000));
+        assert_eq!(serde_json::to_string(&item).unwrap(), json);
+    }
+    
+    #[test]
+    fn round_trips_strings() {
+        let known = MaybeKnown::Known(Killstreaker::HypnoBeam);
+        let unknown = MaybeKnown::<Killstreaker>::Unknown(3000);
+        
+        assert_eq!(known.to_string().parse::<MaybeKnown<Killstreaker>>().unwrap(), known);
+        assert_eq!(unknown.to_string().parse::<MaybeKnown<Killstreaker>>().unwrap(), unknown);
+        assert_eq!("2002".parse::<MaybeKnown<Killstreaker>>().unwrap(), MaybeKnown::Known(Killstreaker::FireHorns));
+        assert!("Not a killstreaker".parse::<MaybeKnown<Killstreaker>>().is_err());
+    }
+    
+    #[test]
+    fn converts_values() {
+        assert_eq!(MaybeKnown::<KillEaterScoreType>::new(0).known(), Some(KillEaterScoreType::Kills));
+        assert_eq!(u32::from(MaybeKnown::<KillEaterScoreType>::Unknown(5000)), 5000);
+        assert_eq!(u32::from(MaybeKnown::from(Origin::Traded)), 3);
+        assert!(MaybeKnown::<Origin>::new(5000).is_unknown());
+    }
+}
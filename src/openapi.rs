@@ -0,0 +1,113 @@
+//! [`ToSchema`] implementations matching the serialized form of each type.
+
+use crate::{AttributeSet, ItemAttribute, SpellSet, StrangePartSet};
+use crate::serialize;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, Type};
+use utoipa::openapi::{Ref, RefOr};
+use utoipa::{PartialSchema, ToSchema};
+
+/// Implements [`ToSchema`] for enums.
+macro_rules! impl_to_schema_enum {
+    ($($t:ident => $name:literal),* $(,)?) => {
+        $(
+            impl PartialSchema for crate::$t {
+                fn schema() -> RefOr<Schema> {
+                    enum_schema::<crate::$t>()
+                }
+            }
+            
+            impl ToSchema for crate::$t {}
+        )*
+    };
+}
+
+/// Implements [`ToSchema`] for attribute sets, which are serialized as arrays of attributes.
+macro_rules! impl_to_schema_attribute_set {
+    ($($t:ident),* $(,)?) => {
+        $(
+            impl PartialSchema for $t {
+                fn schema() -> RefOr<Schema> {
+                    ArrayBuilder::new()
+                        .items(Ref::from_schema_name(ItemAttribute::name()))
+                        .max_items(Some(<$t as AttributeSet>::MAX_COUNT))
+                        .into()
+                }
+            }
+            
+            impl ToSchema for $t {
+                fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+                    schemas.push((ItemAttribute::name().into(), ItemAttribute::schema()));
+                    ItemAttribute::schemas(schemas);
+                }
+            }
+        )*
+    };
+}
+
+for_each_enum!(impl_to_schema_enum);
+
+impl_to_schema_attribute_set!(SpellSet, StrangePartSet);
+
+/// Builds the schema for an enum from the serialized values of its variants.
+fn enum_schema<T: IntoEnumIterator + Serialize>() -> RefOr<Schema> {
+    let values = serialize::enum_values::<T>();
+    let schema_type = if values.iter().all(serde_json::Value::is_u64) {
+        Type::Integer
+    } else {
+        Type::String
+    };
+    
+    ObjectBuilder::new()
+        .schema_type(schema_type)
+        .enum_values(Some(values))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ItemSlot, Quality, Spell};
+    use serde_json::json;
+    use utoipa::OpenApi;
+    
+    fn to_json<T: PartialSchema>() -> serde_json::Value {
+        serde_json::to_value(T::schema()).unwrap()
+    }
+    
+    #[test]
+    fn repr_enums_are_integers() {
+        let schema = to_json::<Quality>();
+        
+        assert_eq!(schema["type"], json!("integer"));
+        assert!(schema["enum"].as_array().unwrap().contains(&json!(5)));
+    }
+    
+    #[test]
+    fn string_enums_are_strings() {
+        let schema = to_json::<ItemSlot>();
+        
+        assert_eq!(schema["type"], json!("string"));
+        assert!(schema["enum"].as_array().unwrap().contains(&json!("primary")));
+        assert!(schema["enum"].as_array().unwrap().contains(&json!("pda2")));
+        assert!(to_json::<Spell>()["enum"].as_array().unwrap().contains(&json!("Halloween Fire")));
+    }
+    
+    #[test]
+    fn sets_include_attribute_schemas() {
+        #[derive(OpenApi)]
+        #[openapi(components(schemas(StrangePartSet, Quality)))]
+        struct Api;
+        
+        let api = serde_json::to_value(Api::openapi()).unwrap();
+        let schemas = &api["components"]["schemas"];
+        
+        assert_eq!(schemas["StrangePartSet"]["type"], json!("array"));
+        assert_eq!(schemas["StrangePartSet"]["items"], json!({ "$ref": "#/components/schemas/ItemAttribute" }));
+        assert_eq!(schemas["StrangePartSet"]["maxItems"], json!(StrangePartSet::MAX_COUNT));
+        assert!(schemas["ItemAttribute"].is_object());
+        assert!(schemas["AttributeValue"].is_object());
+        assert_eq!(schemas["Quality"]["type"], json!("integer"));
+    }
+}
@@ -1,6 +1,6 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Origin.
 #[derive(
@@ -17,14 +17,19 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[non_exhaustive]
 #[allow(missing_docs)]
 pub enum Origin {
+    // codegen:start variants
     #[strum(serialize = "Timed Drop")]
     TimedDrop = 0,
     #[strum(serialize = "Achievement")]
@@ -85,6 +90,7 @@ pub enum Origin {
     WarPaint = 28,
     #[strum(serialize = "Untradable Free Contract Reward")]
     UntradableFreeContractReward = 29,
+    // codegen:end variants
 }
 
 #[cfg(test)]
@@ -6,12 +6,15 @@ use crate::{
     EffectType,
     HasItemDefindex,
     ItemAttribute,
+    Team,
+    TeamColored,
     TryFromIntAttributeValue,
 };
+use crate::color;
 use crate::econ_attributes::SetItemTintRgb2;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Paint. `repr` values are mapped to the corresponding hexadecimal color (represented as a u32).
 /// For team paints, this is the color for RED team.
@@ -29,10 +32,14 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[allow(missing_docs)]
 pub enum Paint {
@@ -176,6 +183,28 @@ impl Paint {
         }
     }
     
+    /// Gets the paint attribute for a team. For BLU, this is the "set_item_tint_rgb_2" attribute,
+    /// otherwise it is the "set_item_tint_rgb" attribute.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Paint, Team};
+    /// 
+    /// let attribute = Paint::TeamSpirit.attribute_for(Team::Blu);
+    /// 
+    /// assert_eq!(attribute.defindex, 261);
+    /// assert_eq!(attribute.float_value, Some(0x5885A2 as f32));
+    /// ```
+    pub fn attribute_for(&self, team: Team) -> ItemAttribute {
+        let float_value = self.color_for(team) as f32;
+        
+        ItemAttribute {
+            defindex: team.set_item_tint_rgb_defindex(),
+            value: float_value.to_bits().into(),
+            float_value: Some(float_value),
+        }
+    }
+    
     /// Determines if this paint is a team-colored paint.
     pub fn is_team_paint(&self) -> bool {
         matches!(
@@ -249,6 +278,18 @@ impl Colored for Paint {
     fn from_color(color: u32) -> Option<Self> {
         Self::try_from(color).ok()
     }
+    
+    /// Gets the colors for both teams.
+    fn match_colors(&self) -> Vec<u32> {
+        color::team_match_colors(self.colors())
+    }
+}
+
+impl TeamColored for Paint {
+    /// Gets the colors for both teams. See [`Paint::colors`].
+    fn colors(&self) -> (u32, u32) {
+        Paint::colors(self)
+    }
 }
 
 impl HasItemDefindex for Paint {
@@ -327,6 +368,7 @@ impl HasItemDefindex for Paint {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use strum::IntoEnumIterator;
     
     #[test]
     fn string_conversions() {
@@ -368,4 +410,33 @@ mod tests {
     fn converts_from_hex_str_lowercase_with_pound() {
         assert_eq!(Paint::from_color_str("#FF69B4").unwrap(), Paint::PinkAsHell);
     }
+    
+    #[test]
+    fn team_colors() {
+        assert_eq!(Paint::TeamSpirit.color_for(Team::Red), 0xB8383B);
+        assert_eq!(Paint::TeamSpirit.color_for(Team::Blu), 0x5885A2);
+        assert_eq!(Paint::from_color_for(Team::Blu, 0x5885A2), Some(Paint::TeamSpirit));
+        assert_eq!(Paint::from_color_for(Team::Red, 0x5885A2), None);
+        assert!(Paint::TeamSpirit.has_team_colors());
+        assert!(!Paint::PinkAsHell.has_team_colors());
+        
+        let attribute = Paint::TeamSpirit.attribute_for(Team::Red);
+        
+        assert_eq!(attribute.defindex, 142);
+        assert_eq!(attribute.float_value, ItemAttribute::from(Paint::TeamSpirit).float_value);
+    }
+    
+    #[test]
+    fn finds_nearest_color() {
+        assert_eq!(Paint::from_color_nearest(0x000000).unwrap().value, Paint::ADistinctiveLackOfHue);
+        assert_eq!(Paint::from_color_nearest(0x256D8E).unwrap().value, Paint::TheValueOfTeamwork);
+        assert_eq!(Paint::from_color_str_nearest("#FEFEFE").unwrap().value, Paint::AnExtraordinaryAbundanceOfTinge);
+        
+        for paint in Paint::iter() {
+            let (red, blu) = paint.colors();
+            
+            assert_eq!(Paint::from_color_nearest(red).unwrap().value, paint);
+            assert_eq!(Paint::from_color_nearest(blu).unwrap().value, paint);
+        }
+    }
 }
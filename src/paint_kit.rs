@@ -0,0 +1,222 @@
+//! War Paint paint kits and a registry for looking them up.
+
+use crate::{Attribute, Grade, ItemAttribute, Registry, RegistryEntry};
+use crate::econ_attributes::{CustomPaintkitSeedHi, CustomPaintkitSeedLo, PaintkitProtoDefIndex};
+use std::borrow::Cow;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// A paint kit, as applied by a War Paint or found on a Decorated Weapon.
+/// 
+/// Paint kits are not an enum as they are added frequently. Use a [`PaintKitRegistry`] to look
+/// them up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PaintKit {
+    /// The ID of the paint kit, as stored in the "paintkit_proto_def_index" (834) attribute.
+    pub id: u32,
+    /// The name of the paint kit, e.g. "Sweet Dreams".
+    pub name: Cow<'static, str>,
+    /// The grade of the paint kit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grade: Option<Grade>,
+    /// The name of the collection the paint kit belongs to, e.g. "Scream Fortress X Collection".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection: Option<Cow<'static, str>>,
+}
+
+impl PaintKit {
+    /// Creates a new paint kit.
+    pub const fn new(id: u32, name: &'static str) -> Self {
+        Self {
+            id,
+            name: Cow::Borrowed(name),
+            grade: None,
+            collection: None,
+        }
+    }
+    
+    /// Gets the paint kit ID from a "paintkit_proto_def_index" (834) attribute.
+    pub fn id_from_attribute(attribute: &ItemAttribute) -> Option<u32> {
+        if attribute.defindex != PaintkitProtoDefIndex::DEFINDEX {
+            return None;
+        }
+        
+        attribute.integer_value()
+    }
+}
+
+impl fmt::Display for PaintKit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A registry of paint kits, with lookups by ID and name.
+/// 
+/// Deserializes from a sequence of paint kits.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{Grade, PaintKitRegistry};
+/// 
+/// let json = r#"[
+///     { "id": 200, "name": "Sweet Dreams", "grade": 4, "collection": "Scream Fortress X Collection" }
+/// ]"#;
+/// let registry: PaintKitRegistry = serde_json::from_str(json).unwrap();
+/// let paint_kit = registry.get_by_name("sweet dreams").unwrap();
+/// 
+/// assert_eq!(paint_kit.id, 200);
+/// assert_eq!(paint_kit.grade, Some(Grade::Commando));
+/// assert_eq!(registry.collection("Scream Fortress X Collection").count(), 1);
+/// ```
+pub type PaintKitRegistry = Registry<PaintKit>;
+
+impl RegistryEntry for PaintKit {
+    fn id(&self) -> u32 {
+        self.id
+    }
+    
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Registry<PaintKit> {
+    /// Gets the paint kit for a "paintkit_proto_def_index" (834) attribute.
+    pub fn get_by_attribute(&self, attribute: &ItemAttribute) -> Option<&PaintKit> {
+        PaintKit::id_from_attribute(attribute).and_then(|id| self.get(id))
+    }
+    
+    /// Iterates over the paint kits in a collection.
+    pub fn collection<'a>(&'a self, collection: &'a str) -> impl Iterator<Item = &'a PaintKit> + 'a {
+        self.iter()
+            .filter(move |paint_kit| paint_kit.collection.as_deref() == Some(collection))
+    }
+}
+
+/// The 64-bit seed used for generating a War Paint's pattern. This is stored as two 32-bit halves
+/// in the "custom_paintkit_seed_lo" (866) and "custom_paintkit_seed_hi" (867) attributes.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{ItemAttribute, PaintKitSeed};
+/// use tf2_enum::econ_attributes::{CustomPaintkitSeedHi, CustomPaintkitSeedLo};
+/// 
+/// let attributes = [
+///     ItemAttribute::from(CustomPaintkitSeedLo(0x89ABCDEF)),
+///     ItemAttribute::from(CustomPaintkitSeedHi(0x01234567)),
+/// ];
+/// let seed = PaintKitSeed::from_attributes(&attributes).unwrap();
+/// 
+/// assert_eq!(seed, PaintKitSeed(0x0123456789ABCDEF));
+/// assert_eq!(seed.lo(), 0x89ABCDEF);
+/// assert_eq!(seed.hi(), 0x01234567);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct PaintKitSeed(pub u64);
+
+impl PaintKitSeed {
+    /// Creates a seed from its lower and upper 32 bits.
+    pub const fn from_parts(lo: u32, hi: u32) -> Self {
+        Self((hi as u64) << 32 | lo as u64)
+    }
+    
+    /// Gets the lower 32 bits.
+    pub const fn lo(&self) -> u32 {
+        self.0 as u32
+    }
+    
+    /// Gets the upper 32 bits.
+    pub const fn hi(&self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+    
+    /// Reassembles the seed from a list of attributes. Returns `None` if neither half is present.
+    /// A missing half is treated as 0.
+    pub fn from_attributes(attributes: &[ItemAttribute]) -> Option<Self> {
+        let find = |defindex: u32| {
+            attributes
+                .iter()
+                .find(|attribute| attribute.defindex == defindex)
+                .and_then(ItemAttribute::integer_value)
+        };
+        let lo = find(CustomPaintkitSeedLo::DEFINDEX);
+        let hi = find(CustomPaintkitSeedHi::DEFINDEX);
+        
+        if lo.is_none() && hi.is_none() {
+            return None;
+        }
+        
+        Some(Self::from_parts(lo.unwrap_or_default(), hi.unwrap_or_default()))
+    }
+    
+    /// Splits the seed into its "custom_paintkit_seed_lo" and "custom_paintkit_seed_hi"
+    /// attributes.
+    pub fn to_attributes(&self) -> [ItemAttribute; 2] {
+        [
+            CustomPaintkitSeedLo(self.lo()).into(),
+            CustomPaintkitSeedHi(self.hi()).into(),
+        ]
+    }
+}
+
+impl From<u64> for PaintKitSeed {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PaintKitSeed> for u64 {
+    fn from(seed: PaintKitSeed) -> Self {
+        seed.0
+    }
+}
+
+impl fmt::Display for PaintKitSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn splits_seed() {
+        let seed = PaintKitSeed(u64::MAX - 1);
+        let [lo, hi] = seed.to_attributes();
+        
+        assert_eq!(lo.defindex, 866);
+        assert_eq!(hi.defindex, 867);
+        assert_eq!(PaintKitSeed::from_attributes(&[hi, lo]), Some(seed));
+        assert_eq!(PaintKitSeed::from_parts(seed.lo(), seed.hi()), seed);
+    }
+    
+    #[test]
+    fn seed_from_missing_half() {
+        let attributes = [ItemAttribute::from(CustomPaintkitSeedLo(5))];
+        
+        assert_eq!(PaintKitSeed::from_attributes(&attributes), Some(PaintKitSeed(5)));
+        assert_eq!(PaintKitSeed::from_attributes(&[]), None);
+    }
+    
+    #[test]
+    fn registry_lookups() {
+        let mut registry = PaintKitRegistry::new();
+        
+        registry.insert(PaintKit::new(200, "Sweet Dreams"));
+        registry.insert(PaintKit {
+            grade: Some(Grade::Assassin),
+            ..PaintKit::new(201, "Mister Cuddles")
+        });
+        
+        let attribute = ItemAttribute::from(PaintkitProtoDefIndex(201));
+        
+        assert_eq!(registry.get_by_attribute(&attribute).unwrap().name, "Mister Cuddles");
+        assert_eq!(registry.get_by_name("SWEET DREAMS").unwrap().id, 200);
+        assert_eq!(registry.insert(PaintKit::new(200, "Sweet Dreams")).unwrap().id, 200);
+        assert_eq!(registry.len(), 2);
+    }
+}
@@ -0,0 +1,357 @@
+//! Unusual particle effects and a registry for looking them up.
+
+use crate::{Attribute, AttributeValue, ItemAttribute, Registry, RegistryEntry};
+use crate::econ_attributes::{SetAttachedParticle, TauntAttachParticleIndex};
+use std::borrow::Cow;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// An unusual particle effect, as listed in the `attribute_controlled_attached_particles` section
+/// of the schema.
+/// 
+/// Particle effects are not an enum as they are added frequently. Use a [`ParticleEffectRegistry`]
+/// loaded from the schema to look them up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ParticleEffect {
+    /// The ID of the effect, as stored in the "attach particle effect" (134) and "taunt attach
+    /// particle index" (2041) attributes.
+    pub id: u32,
+    /// The name of the effect, e.g. "Burning Flames".
+    pub name: Cow<'static, str>,
+    /// The name of the particle system, e.g. "superrare_burning1".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<Cow<'static, str>>,
+    /// Whether the effect is attached to the root bone of the model.
+    #[serde(default)]
+    pub attach_to_rootbone: bool,
+}
+
+impl ParticleEffect {
+    /// Creates a new particle effect.
+    pub const fn new(id: u32, name: &'static str) -> Self {
+        Self {
+            id,
+            name: Cow::Borrowed(name),
+            system: None,
+            attach_to_rootbone: false,
+        }
+    }
+    
+    /// Gets the effect ID from an "attach particle effect" (134) or "taunt attach particle index"
+    /// (2041) attribute.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{ItemAttribute, ParticleEffect};
+    /// use tf2_enum::econ_attributes::TauntAttachParticleIndex;
+    /// 
+    /// let attribute = ItemAttribute::from(TauntAttachParticleIndex(3001));
+    /// 
+    /// assert_eq!(ParticleEffect::id_from_attribute(&attribute), Some(3001));
+    /// ```
+    pub fn id_from_attribute(attribute: &ItemAttribute) -> Option<u32> {
+        match attribute.defindex {
+            // Stored as a float.
+            SetAttachedParticle::DEFINDEX => attribute.float_value
+                .or(match attribute.value {
+                    AttributeValue::Integer(bits) => Some(f32::from_bits(bits)),
+                    AttributeValue::Float(value) => Some(value),
+                    _ => None,
+                })
+                .and_then(u32_from_float),
+            // Stored as an integer.
+            TauntAttachParticleIndex::DEFINDEX => attribute.integer_value(),
+            _ => None,
+        }
+    }
+    
+    /// Checks if this is a taunt effect.
+    /// 
+    /// This is a heuristic based on the ID ranges in the schema, where taunt effects start at
+    /// 3000 and other effects are below it. The schema has no flag for this, so an effect added
+    /// outside of these ranges may be misclassified.
+    pub fn is_taunt_effect(&self) -> bool {
+        self.id >= 3000
+    }
+}
+
+impl fmt::Display for ParticleEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A registry of particle effects, with lookups by ID and name.
+/// 
+/// Deserializes from a sequence of effects, such as the `attribute_controlled_attached_particles`
+/// section of the schema.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{ItemAttribute, ParticleEffectRegistry};
+/// 
+/// let json = r#"[
+///     { "system": "superrare_burning1", "id": 13, "attach_to_rootbone": false, "name": "Burning Flames" }
+/// ]"#;
+/// let registry: ParticleEffectRegistry = serde_json::from_str(json).unwrap();
+/// let attributes = [ItemAttribute {
+///     defindex: 134,
+///     value: 13f32.to_bits().into(),
+///     float_value: Some(13.0),
+/// }];
+/// 
+/// assert_eq!(registry.get(13).unwrap().name, "Burning Flames");
+/// assert_eq!(registry.get_by_name("burning flames").unwrap().id, 13);
+/// assert_eq!(registry.get_by_attributes(&attributes).unwrap().id, 13);
+/// ```
+pub type ParticleEffectRegistry = Registry<ParticleEffect>;
+
+impl RegistryEntry for ParticleEffect {
+    fn id(&self) -> u32 {
+        self.id
+    }
+    
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Registry<ParticleEffect> {
+    /// Creates a registry from the snapshot of particle effects bundled with this crate. The
+    /// snapshot only includes effects which existed when it was taken, so prefer loading effects
+    /// from the schema where possible.
+    /// 
+    /// Requires the `particle-effects-snapshot` feature.
+    #[cfg(feature = "particle-effects-snapshot")]
+    pub fn snapshot() -> Self {
+        snapshot::PARTICLE_EFFECTS.iter().cloned().collect()
+    }
+    
+    /// Gets the effect for an "attach particle effect" (134) or "taunt attach particle index"
+    /// (2041) attribute.
+    pub fn get_by_attribute(&self, attribute: &ItemAttribute) -> Option<&ParticleEffect> {
+        ParticleEffect::id_from_attribute(attribute).and_then(|id| self.get(id))
+    }
+    
+    /// Gets the effect from a list of attributes.
+    pub fn get_by_attributes(&self, attributes: &[ItemAttribute]) -> Option<&ParticleEffect> {
+        attributes.iter().find_map(|attribute| self.get_by_attribute(attribute))
+    }
+}
+
+fn u32_from_float(value: f32) -> Option<u32> {
+    if value.fract() != 0.0 || value.is_sign_negative() || value > (u32::MAX as f32) {
+        return None;
+    }
+    
+    Some(value as u32)
+}
+
+#[cfg(feature = "particle-effects-snapshot")]
+mod snapshot {
+    use super::ParticleEffect;
+    
+    /// A snapshot of common particle effects.
+    pub const PARTICLE_EFFECTS: &[ParticleEffect] = &[
+        ParticleEffect::new(2, "Flying Bits"),
+        ParticleEffect::new(3, "Nemesis Burst"),
+        ParticleEffect::new(4, "Community Sparkle"),
+        ParticleEffect::new(5, "Holy Glow"),
+        ParticleEffect::new(6, "Green Confetti"),
+        ParticleEffect::new(7, "Purple Confetti"),
+        ParticleEffect::new(8, "Haunted Ghosts"),
+        ParticleEffect::new(9, "Green Energy"),
+        ParticleEffect::new(10, "Purple Energy"),
+        ParticleEffect::new(11, "Circling TF Logo"),
+        ParticleEffect::new(12, "Massed Flies"),
+        ParticleEffect::new(13, "Burning Flames"),
+        ParticleEffect::new(14, "Scorching Flames"),
+        ParticleEffect::new(15, "Searing Plasma"),
+        ParticleEffect::new(16, "Vivid Plasma"),
+        ParticleEffect::new(17, "Sunbeams"),
+        ParticleEffect::new(18, "Circling Peace Sign"),
+        ParticleEffect::new(19, "Circling Heart"),
+        ParticleEffect::new(20, "Map Stamps"),
+        ParticleEffect::new(28, "Pipe Smoke"),
+        ParticleEffect::new(29, "Stormy Storm"),
+        ParticleEffect::new(30, "Blizzardy Storm"),
+        ParticleEffect::new(31, "Nuts n' Bolts"),
+        ParticleEffect::new(32, "Orbiting Planets"),
+        ParticleEffect::new(33, "Orbiting Fire"),
+        ParticleEffect::new(34, "Bubbling"),
+        ParticleEffect::new(35, "Smoking"),
+        ParticleEffect::new(36, "Steaming"),
+        ParticleEffect::new(37, "Flaming Lantern"),
+        ParticleEffect::new(38, "Cloudy Moon"),
+        ParticleEffect::new(39, "Cauldron Bubbles"),
+        ParticleEffect::new(40, "Eerie Orbiting Fire"),
+        ParticleEffect::new(43, "Knifestorm"),
+        ParticleEffect::new(44, "Misty Skull"),
+        ParticleEffect::new(45, "Harvest Moon"),
+        ParticleEffect::new(46, "It's A Secret To Everybody"),
+        ParticleEffect::new(47, "Stormy 13th Hour"),
+        ParticleEffect::new(56, "Kill-a-Watt"),
+        ParticleEffect::new(57, "Terror-Watt"),
+        ParticleEffect::new(58, "Cloud 9"),
+        ParticleEffect::new(59, "Aces High"),
+        ParticleEffect::new(60, "Dead Presidents"),
+        ParticleEffect::new(61, "Miami Nights"),
+        ParticleEffect::new(62, "Disco Beat Down"),
+        ParticleEffect::new(63, "Phosphorous"),
+        ParticleEffect::new(64, "Sulphurous"),
+        ParticleEffect::new(65, "Memory Leak"),
+        ParticleEffect::new(66, "Overclocked"),
+        ParticleEffect::new(67, "Electrostatic"),
+        ParticleEffect::new(68, "Power Surge"),
+        ParticleEffect::new(69, "Anti-Freeze"),
+        ParticleEffect::new(70, "Time Warp"),
+        ParticleEffect::new(71, "Green Black Hole"),
+        ParticleEffect::new(72, "Roboactive"),
+        ParticleEffect::new(73, "Arcana"),
+        ParticleEffect::new(74, "Spellbound"),
+        ParticleEffect::new(75, "Chiroptera Venenata"),
+        ParticleEffect::new(76, "Poisoned Shadows"),
+        ParticleEffect::new(77, "Something Burning This Way Comes"),
+        ParticleEffect::new(78, "Hellfire"),
+        ParticleEffect::new(79, "Darkblaze"),
+        ParticleEffect::new(80, "Demonflame"),
+        ParticleEffect::new(81, "Bonzo The All-Gnawing"),
+        ParticleEffect::new(82, "Amaranthine"),
+        ParticleEffect::new(83, "Stare From Beyond"),
+        ParticleEffect::new(84, "The Ooze"),
+        ParticleEffect::new(85, "Ghastly Ghosts Jr"),
+        ParticleEffect::new(86, "Haunted Phantasm Jr"),
+        ParticleEffect::new(87, "Frostbite"),
+        ParticleEffect::new(88, "Molten Mallard"),
+        ParticleEffect::new(89, "Morning Glory"),
+        ParticleEffect::new(90, "Death at Dusk"),
+        ParticleEffect::new(91, "Abduction"),
+        ParticleEffect::new(92, "Atomic"),
+        ParticleEffect::new(93, "Subatomic"),
+        ParticleEffect::new(94, "Electric Hat Protector"),
+        ParticleEffect::new(95, "Magnetic Hat Protector"),
+        ParticleEffect::new(96, "Voltaic Hat Protector"),
+        ParticleEffect::new(97, "Galactic Codex"),
+        ParticleEffect::new(98, "Ancient Codex"),
+        ParticleEffect::new(99, "Nebula"),
+        ParticleEffect::new(100, "Death by Disco"),
+        ParticleEffect::new(101, "It's a mystery to everyone"),
+        ParticleEffect::new(102, "It's a puzzle to me"),
+        ParticleEffect::new(103, "Ether Trail"),
+        ParticleEffect::new(104, "Nether Trail"),
+        ParticleEffect::new(105, "Ancient Eldritch"),
+        ParticleEffect::new(106, "Eldritch Flame"),
+        ParticleEffect::new(107, "Neutron Star"),
+        ParticleEffect::new(108, "Tesla Coil"),
+        ParticleEffect::new(109, "Starstorm Insomnia"),
+        ParticleEffect::new(110, "Starstorm Slumber"),
+        ParticleEffect::new(701, "Hot"),
+        ParticleEffect::new(702, "Isotope"),
+        ParticleEffect::new(703, "Cool"),
+        ParticleEffect::new(704, "Energy Orb"),
+        ParticleEffect::new(3001, "Showstopper"),
+        ParticleEffect::new(3002, "Showstopper"),
+        ParticleEffect::new(3003, "Holy Grail"),
+        ParticleEffect::new(3004, "'72"),
+        ParticleEffect::new(3005, "Fountain of Delight"),
+        ParticleEffect::new(3006, "Screaming Tiger"),
+        ParticleEffect::new(3007, "Skill Gotten Gains"),
+        ParticleEffect::new(3008, "Midnight Whirlwind"),
+        ParticleEffect::new(3009, "Silver Cyclone"),
+        ParticleEffect::new(3010, "Mega Strike"),
+        ParticleEffect::new(3011, "Haunted Phantasm"),
+        ParticleEffect::new(3012, "Ghastly Ghosts"),
+        ParticleEffect::new(3013, "Hellish Inferno"),
+        ParticleEffect::new(3014, "Spectral Swirl"),
+        ParticleEffect::new(3015, "Infernal Flames"),
+        ParticleEffect::new(3016, "Infernal Smoke"),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn registry() -> ParticleEffectRegistry {
+        [
+            ParticleEffect::new(13, "Burning Flames"),
+            ParticleEffect::new(3001, "Showstopper"),
+            ParticleEffect::new(3002, "Showstopper"),
+        ].into_iter().collect()
+    }
+    
+    #[test]
+    fn decodes_attributes() {
+        let registry = registry();
+        let unusual = ItemAttribute {
+            defindex: SetAttachedParticle::DEFINDEX,
+            value: 13f32.to_bits().into(),
+            float_value: Some(13.0),
+        };
+        let taunt = ItemAttribute::from(TauntAttachParticleIndex(3002));
+        
+        assert_eq!(registry.get_by_attribute(&unusual).unwrap().name, "Burning Flames");
+        assert_eq!(registry.get_by_attribute(&taunt).unwrap().id, 3002);
+        assert!(registry.get_by_attribute(&taunt).unwrap().is_taunt_effect());
+        
+        let without_float_value = ItemAttribute {
+            float_value: None,
+            ..unusual
+        };
+        
+        assert_eq!(ParticleEffect::id_from_attribute(&without_float_value), Some(13));
+    }
+    
+    #[test]
+    fn looks_up_by_name() {
+        let registry = registry();
+        
+        assert_eq!(registry.get_by_name("SHOWSTOPPER").unwrap().id, 3001);
+        assert!(registry.get_by_name("Sunbeams").is_none());
+    }
+    
+    #[test]
+    fn replaces_by_id() {
+        let mut registry = registry();
+        let previous = registry.insert(ParticleEffect::new(13, "Burning Flames (Renamed)"));
+        
+        assert_eq!(previous.unwrap().name, "Burning Flames");
+        assert_eq!(registry.len(), 3);
+        assert!(registry.get_by_name("Burning Flames").is_none());
+        assert_eq!(registry.get_by_name("burning flames (renamed)").unwrap().id, 13);
+    }
+    
+    #[test]
+    fn reindexes_shared_names() {
+        let mut registry = registry();
+        
+        registry.insert(ParticleEffect::new(3001, "Showstopper (RED)"));
+        
+        assert_eq!(registry.get_by_name("showstopper").unwrap().id, 3002);
+        assert_eq!(registry.get_by_name("showstopper (red)").unwrap().id, 3001);
+        
+        registry.insert(ParticleEffect::new(3001, "Showstopper"));
+        
+        assert_eq!(registry.get_by_name("showstopper").unwrap().id, 3001);
+        assert!(registry.get_by_name("showstopper (red)").is_none());
+    }
+    
+    #[test]
+    fn serializes() {
+        let registry = registry();
+        let json = serde_json::to_string(&registry).unwrap();
+        let deserialized: ParticleEffectRegistry = serde_json::from_str(&json).unwrap();
+        
+        assert_eq!(deserialized.len(), 3);
+        assert!(json.starts_with(r#"[{"id":13,"name":"Burning Flames","attach_to_rootbone":false}"#));
+    }
+    
+    #[cfg(feature = "particle-effects-snapshot")]
+    #[test]
+    fn loads_snapshot() {
+        let registry = ParticleEffectRegistry::snapshot();
+        
+        assert_eq!(registry.get(13).unwrap().name, "Burning Flames");
+        assert_eq!(registry.len(), snapshot::PARTICLE_EFFECTS.len());
+    }
+}
@@ -0,0 +1,289 @@
+//! Postgres `ENUM` types for the enums in this crate.
+//! 
+//! The `sqlx-postgres-0_8` feature stores enums as `TEXT` or integers. Enums can instead be bound
+//! to a named `ENUM` type, e.g. `tf2_quality`, by wrapping them in `PgEnum` with the same
+//! feature. The labels of each type are the [`Display`][`fmt::Display`] output of each variant,
+//! in the order of [`IntoEnumIterator`].
+//! 
+//! [`PgEnumDef`] generates the `CREATE TYPE` and `ALTER TYPE ... ADD VALUE` statements for
+//! migrations, so that the types stay in sync when variants are added. Generating the
+//! statements does not require any features.
+//! 
+//! # Examples
+//! ```
+//! use tf2_enum::KillstreakTier;
+//! use tf2_enum::pg_enum::PgEnumDef;
+//! 
+//! let def = PgEnumDef::of::<KillstreakTier>();
+//! 
+//! assert_eq!(
+//!     def.create_type(),
+//!     "CREATE TYPE tf2_killstreak_tier AS ENUM ('Killstreak', 'Specialized Killstreak', 'Professional Killstreak');",
+//! );
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+/// An enum which can be stored as a Postgres `ENUM` type. The labels of the type are the
+/// [`Display`][`fmt::Display`] output of each variant, which must be unique and parse back into the
+/// same variant with [`FromStr`].
+pub trait PgEnumType: IntoEnumIterator + fmt::Display + FromStr {
+    /// The name of the `ENUM` type, e.g. "tf2_quality".
+    const TYPE_NAME: &'static str;
+    /// The name of the array type, e.g. "_tf2_quality".
+    const ARRAY_TYPE_NAME: &'static str;
+}
+
+/// Implements [`PgEnumType`] for each enum, skipping the enums matched by the first rules.
+macro_rules! impl_pg_enum_types {
+    // Flags are combined as bits, so not every value is a variant.
+    (@filter [$($done:tt)*] Flags => $name:literal, $($rest:tt)*) => {
+        impl_pg_enum_types!(@filter [$($done)*] $($rest)*);
+    };
+    // Several score types share a name, so their labels would not be unique.
+    (@filter [$($done:tt)*] KillEaterScoreType => $name:literal, $($rest:tt)*) => {
+        impl_pg_enum_types!(@filter [$($done)*] $($rest)*);
+    };
+    (@filter [$($done:tt)*] $t:ident => $name:literal, $($rest:tt)*) => {
+        impl_pg_enum_types!(@filter [$($done)* $t => $name,] $($rest)*);
+    };
+    (@filter [$($t:ident => $name:literal,)*]) => {
+        $(
+            impl PgEnumType for crate::$t {
+                const TYPE_NAME: &'static str = concat!("tf2_", $name);
+                const ARRAY_TYPE_NAME: &'static str = concat!("_tf2_", $name);
+            }
+        )*
+        
+        impl PgEnumDef {
+            /// Gets the definitions of every enum in this crate which implements [`PgEnumType`].
+            pub fn all() -> Vec<Self> {
+                vec![$(Self::of::<crate::$t>()),*]
+            }
+        }
+        
+        /// Checks that the label of every variant parses back into the variant.
+        #[cfg(test)]
+        fn assert_labels_parse() {
+            $(
+                for value in <crate::$t as IntoEnumIterator>::iter() {
+                    assert_eq!(value.to_string().parse::<crate::$t>().ok(), Some(value));
+                }
+            )*
+        }
+    };
+    ($($list:tt)*) => {
+        impl_pg_enum_types!(@filter [] $($list)*);
+    };
+}
+
+for_each_enum!(impl_pg_enum_types);
+
+/// The name and labels of a Postgres `ENUM` type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgEnumDef {
+    /// The name of the type.
+    pub type_name: &'static str,
+    /// The labels of the type, in order.
+    pub labels: Vec<String>,
+}
+
+impl PgEnumDef {
+    /// Gets the definition of an enum.
+    pub fn of<T: PgEnumType>() -> Self {
+        Self {
+            type_name: T::TYPE_NAME,
+            labels: T::iter().map(|value| value.to_string()).collect(),
+        }
+    }
+    
+    /// Generates the `CREATE TYPE` statement for this type.
+    pub fn create_type(&self) -> String {
+        let labels = self.labels
+            .iter()
+            .map(|label| quote(label))
+            .collect::<Vec<_>>()
+            .join(", ");
+        
+        format!("CREATE TYPE {} AS ENUM ({labels});", self.type_name)
+    }
+    
+    /// Generates the `ALTER TYPE ... ADD VALUE` statements for the labels which are not in
+    /// `existing`, the labels currently in the database. Each label is added after the label
+    /// which precedes it, or before the first existing label if it is the first label, so the
+    /// order of the type matches the enum. Returns an empty list if
+    /// the type is up to date.
+    /// 
+    /// Labels which are in the database but not in the enum are left alone, as Postgres cannot
+    /// remove values from an `ENUM` type. Before Postgres 12, these statements cannot be run
+    /// inside a transaction.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::KillstreakTier;
+    /// use tf2_enum::pg_enum::PgEnumDef;
+    /// 
+    /// let def = PgEnumDef::of::<KillstreakTier>();
+    /// 
+    /// assert_eq!(def.add_values(&["Killstreak", "Specialized Killstreak"]), vec![
+    ///     "ALTER TYPE tf2_killstreak_tier ADD VALUE IF NOT EXISTS 'Professional Killstreak' AFTER 'Specialized Killstreak';",
+    /// ]);
+    /// assert!(def.add_values(&def.labels).is_empty());
+    /// ```
+    pub fn add_values<S: AsRef<str>>(&self, existing: &[S]) -> Vec<String> {
+        let exists = |label: &str| existing.iter().any(|existing| existing.as_ref() == label);
+        let mut statements = Vec::new();
+        
+        for (i, label) in self.labels.iter().enumerate() {
+            if exists(label) {
+                continue;
+            }
+            
+            let mut statement = format!(
+                "ALTER TYPE {} ADD VALUE IF NOT EXISTS {}",
+                self.type_name,
+                quote(label),
+            );
+            
+            if let Some(previous) = i.checked_sub(1).map(|i| &self.labels[i]) {
+                statement.push_str(&format!(" AFTER {}", quote(previous)));
+            } else if let Some(next) = self.labels.iter().skip(1).find(|label| exists(label)) {
+                statement.push_str(&format!(" BEFORE {}", quote(next)));
+            }
+            
+            statement.push(';');
+            statements.push(statement);
+        }
+        
+        statements
+    }
+    
+    /// Generates the statements to bring the type up to date. If `existing` is `None`, the type
+    /// does not exist yet and the `CREATE TYPE` statement is returned.
+    pub fn migration<S: AsRef<str>>(&self, existing: Option<&[S]>) -> Vec<String> {
+        match existing {
+            Some(existing) => self.add_values(existing),
+            None => vec![self.create_type()],
+        }
+    }
+}
+
+/// Quotes a label as an SQL string literal.
+fn quote(label: &str) -> String {
+    format!("'{}'", label.replace('\'', "''"))
+}
+
+/// Binds an enum to its Postgres `ENUM` type rather than `TEXT` or an integer.
+/// 
+/// # Examples
+/// ```no_run
+/// # async fn example(pool: sqlx::PgPool) -> Result<(), sqlx::Error> {
+/// use tf2_enum::Quality;
+/// use tf2_enum::pg_enum::PgEnum;
+/// 
+/// // CREATE TABLE items (quality tf2_quality NOT NULL)
+/// sqlx::query("INSERT INTO items (quality) VALUES ($1)")
+///     .bind(PgEnum(Quality::Strange))
+///     .execute(&pool)
+///     .await?;
+/// 
+/// let (PgEnum(quality),): (PgEnum<Quality>,) = sqlx::query_as("SELECT quality FROM items")
+///     .fetch_one(&pool)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "sqlx-postgres-0_8")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgEnum<T>(pub T);
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<T: PgEnumType> ::sqlx::Type<::sqlx::Postgres> for PgEnum<T> {
+    fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+        ::sqlx::postgres::PgTypeInfo::with_name(T::TYPE_NAME)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<'r, T: PgEnumType> ::sqlx::Decode<'r, ::sqlx::Postgres> for PgEnum<T> {
+    fn decode(
+        value: <::sqlx::Postgres as ::sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+        let label = <&str as ::sqlx::Decode<'r, ::sqlx::Postgres>>::decode(value)?;
+        
+        label
+            .parse()
+            .map(PgEnum)
+            .map_err(|_| format!("Invalid label for {}: {}", T::TYPE_NAME, label).into())
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<T: PgEnumType> ::sqlx::Encode<'_, ::sqlx::Postgres> for PgEnum<T> {
+    fn encode_by_ref(&self, buf: &mut ::sqlx::postgres::PgArgumentBuffer) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let s = self.0.to_string();
+        <&str as ::sqlx::Encode<'_, ::sqlx::Postgres>>::encode(&s, buf)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<T: PgEnumType> ::sqlx::postgres::PgHasArrayType for PgEnum<T> {
+    fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+        ::sqlx::postgres::PgTypeInfo::with_name(T::ARRAY_TYPE_NAME)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<T> From<T> for PgEnum<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Wear;
+    use std::collections::HashSet;
+    
+    #[test]
+    fn labels_are_valid() {
+        for def in PgEnumDef::all() {
+            let unique = def.labels.iter().collect::<HashSet<_>>();
+            
+            assert_eq!(unique.len(), def.labels.len(), "{} has duplicate labels", def.type_name);
+            // Postgres truncates labels longer than NAMEDATALEN - 1 bytes.
+            assert!(def.labels.iter().all(|label| !label.is_empty() && label.len() <= 63));
+        }
+    }
+    
+    #[test]
+    fn labels_parse() {
+        assert_labels_parse();
+    }
+    
+    #[test]
+    fn quotes_labels() {
+        let def = PgEnumDef {
+            type_name: "tf2_test",
+            labels: vec!["A".into(), "Hale's Own".into()],
+        };
+        
+        assert_eq!(def.create_type(), "CREATE TYPE tf2_test AS ENUM ('A', 'Hale''s Own');");
+    }
+    
+    #[test]
+    fn adds_values_in_order() {
+        let def = PgEnumDef::of::<Wear>();
+        let existing = ["Field-Tested", "Well-Worn"];
+        
+        assert_eq!(def.migration(Some(&existing)), vec![
+            "ALTER TYPE tf2_wear ADD VALUE IF NOT EXISTS 'Factory New' BEFORE 'Field-Tested';",
+            "ALTER TYPE tf2_wear ADD VALUE IF NOT EXISTS 'Minimal Wear' AFTER 'Factory New';",
+            "ALTER TYPE tf2_wear ADD VALUE IF NOT EXISTS 'Battle Scarred' AFTER 'Well-Worn';",
+        ]);
+        assert_eq!(def.migration::<&str>(None), vec![def.create_type()]);
+    }
+}
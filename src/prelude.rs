@@ -18,6 +18,7 @@ pub use crate::{
     AttributeSet,
     TryFromIntAttributeValue,
     Colored,
+    TeamColored,
     HasItemDefindex,
 };
 
@@ -31,13 +32,16 @@ pub use crate::{
     KillEaterScoreType,
     KillstreakTier,
     Killstreaker,
+    MaybeKnown,
     Origin,
     Paint,
+    PaintKitSeed,
     PaintSpell,
     Quality,
     Sheen,
     Spell,
     StrangePart,
+    Team,
     Wear,
 };
 
@@ -48,5 +52,5 @@ pub use crate::{
 };
 
 // External crates
-pub use strum::{EnumCount, IntoEnumIterator};
+pub use strum::{EnumCount, EnumMessage, IntoEnumIterator};
 pub use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -1,7 +1,7 @@
 use crate::Colored;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Quality.
 #[derive(
@@ -18,13 +18,18 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[allow(missing_docs)]
 pub enum Quality {
+    // codegen:start variants
     Normal = 0,
     Genuine = 1,
     #[strum(serialize = "rarity2")]
@@ -46,6 +51,7 @@ pub enum Quality {
     Collectors = 14,
     #[strum(serialize = "Decorated Weapon")]
     DecoratedWeapon = 15,
+    // codegen:end variants
 }
 
 impl Colored for Quality {
@@ -1,10 +1,9 @@
 use crate::Grade;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
-/// Kill eater score type. Conversion from strings is not supported due to multiple variants
-/// having the same string representation. They can still be formatted into strings.
+/// Rarity. The internal values for each [`Grade`].
 #[derive(
     Serialize_repr,
     Deserialize_repr,
@@ -15,13 +14,18 @@ use strum::{Display, EnumCount, EnumIter};
     Ord,
     PartialOrd,
     Display,
+    EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
     Clone,
     Copy,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 #[repr(u32)]
@@ -0,0 +1,159 @@
+//! A registry of entries which are loaded at runtime, with lookups by ID and name.
+
+use crate::RegistryEntry;
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// A registry of entries, with lookups by ID and name. This is used for values which are not
+/// enums as they are added frequently, such as [`PaintKit`][crate::PaintKit] and
+/// [`ParticleEffect`][crate::ParticleEffect].
+/// 
+/// Deserializes from a sequence of entries.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{ParticleEffect, Registry};
+/// 
+/// let registry = [
+///     ParticleEffect::new(13, "Burning Flames"),
+///     ParticleEffect::new(14, "Scorching Flames"),
+/// ].into_iter().collect::<Registry<_>>();
+/// 
+/// assert_eq!(registry.get(13).unwrap().name, "Burning Flames");
+/// assert_eq!(registry.get_by_name("scorching flames").unwrap().id, 14);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Registry<T> {
+    entries: Vec<T>,
+    by_id: HashMap<u32, usize>,
+    by_name: HashMap<String, usize>,
+}
+
+impl<T> Default for Registry<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            by_id: HashMap::new(),
+            by_name: HashMap::new(),
+        }
+    }
+}
+
+impl<T: RegistryEntry> Registry<T> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Inserts an entry. If an entry with the same ID exists it is replaced and returned.
+    pub fn insert(&mut self, entry: T) -> Option<T> {
+        if let Some(index) = self.by_id.get(&entry.id()).copied() {
+            let previous = std::mem::replace(&mut self.entries[index], entry);
+            
+            self.reindex_name(index, previous.name());
+            return Some(previous);
+        }
+        
+        let index = self.entries.len();
+        
+        self.by_id.insert(entry.id(), index);
+        self.by_name.entry(entry.name().to_lowercase()).or_insert(index);
+        self.entries.push(entry);
+        None
+    }
+    
+    /// Updates the name index after the entry at `index` was renamed from `previous_name`.
+    fn reindex_name(&mut self, index: usize, previous_name: &str) {
+        let previous_name = previous_name.to_lowercase();
+        let name = self.entries[index].name().to_lowercase();
+        
+        if previous_name == name {
+            return;
+        }
+        
+        // Another entry with the previous name may now be the first with that name.
+        if self.by_name.get(&previous_name) == Some(&index) {
+            match self.entries.iter().position(|entry| entry.name().to_lowercase() == previous_name) {
+                Some(i) => self.by_name.insert(previous_name, i),
+                None => self.by_name.remove(&previous_name),
+            };
+        }
+        
+        let first = self.by_name.entry(name).or_insert(index);
+        
+        *first = (*first).min(index);
+    }
+    
+    /// Gets an entry by its ID.
+    pub fn get(&self, id: u32) -> Option<&T> {
+        self.by_id.get(&id).map(|i| &self.entries[*i])
+    }
+    
+    /// Gets an entry by its name, ignoring case. If several entries share a name (e.g.
+    /// team-colored taunt effects), the first inserted is returned.
+    pub fn get_by_name(&self, name: &str) -> Option<&T> {
+        self.by_name.get(&name.to_lowercase()).map(|i| &self.entries[*i])
+    }
+    
+    /// Iterates over the entries in the order they were inserted.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.entries.iter()
+    }
+    
+    /// The number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Checks if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T: RegistryEntry> Extend<T> for Registry<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for entry in iter {
+            self.insert(entry);
+        }
+    }
+}
+
+impl<T: RegistryEntry> FromIterator<T> for Registry<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        
+        registry.extend(iter);
+        registry
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Registry<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Registry<T>
+where
+    T: RegistryEntry + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::<T>::deserialize(deserializer).map(Self::from_iter)
+    }
+}
+
+impl<T: Serialize> Serialize for Registry<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.entries.iter())
+    }
+}
@@ -0,0 +1,342 @@
+//! Models the customizations removed by restoring an item.
+
+use crate::{
+    Attribute,
+    Attributes,
+    ItemAttribute,
+    KillstreakTier,
+    Killstreaker,
+    Paint,
+    Sheen,
+    StrangePart,
+    TryFromIntAttributeValue,
+};
+use crate::econ_attributes::{
+    CustomDescAttr,
+    CustomNameAttr,
+    IsFestivized,
+    KillEaterUserScore,
+    SetItemTintRgb2,
+};
+
+/// A customization which can be individually removed from an item using the "Restore" option
+/// ([`Capability::CanBeRestored`][`crate::Capability::CanBeRestored`]).
+/// 
+/// Spells are not included as they cannot be removed once applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Customization {
+    /// A paint. Includes both the "set_item_tint_rgb" and "set_item_tint_rgb_2" attributes. `None`
+    /// if the item is painted with an unknown color.
+    Paint(Option<Paint>),
+    /// A custom name from a Name Tag.
+    CustomName,
+    /// A custom description from a Description Tag.
+    CustomDescription,
+    /// A strange part. Includes both the score type and its score counter.
+    StrangePart(StrangePart),
+    /// A killstreak from a Killstreak Kit. Includes the sheen and killstreaker attributes. `None`
+    /// if the item has a sheen or killstreaker without a known killstreak tier.
+    Killstreak(Option<KillstreakTier>),
+    /// A Festivizer.
+    Festivizer,
+}
+
+impl Customization {
+    /// Gets the attribute `defindex`es this customization is stored in. For strange parts, this
+    /// includes the defindexes of all three part slots as a part may be in any of them.
+    pub fn attribute_defindexes(&self) -> &'static [u32] {
+        match self {
+            Self::Paint(_) => &[
+                Paint::DEFINDEX,
+                SetItemTintRgb2::DEFINDEX,
+            ],
+            Self::CustomName => &[CustomNameAttr::DEFINDEX],
+            Self::CustomDescription => &[CustomDescAttr::DEFINDEX],
+            Self::StrangePart(_) => StrangePart::DEFINDEX,
+            Self::Killstreak(_) => &[
+                KillstreakTier::DEFINDEX,
+                Sheen::DEFINDEX,
+                Killstreaker::DEFINDEX,
+            ],
+            Self::Festivizer => &[IsFestivized::DEFINDEX],
+        }
+    }
+    
+    /// Removes this customization from a list of attributes. Returns whether any attributes were
+    /// removed.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Customization, ItemAttribute, Paint};
+    /// 
+    /// let mut attributes = vec![ItemAttribute::from(Paint::PinkAsHell)];
+    /// 
+    /// assert!(Customization::Paint(Some(Paint::PinkAsHell)).remove_from(&mut attributes));
+    /// assert!(attributes.is_empty());
+    /// ```
+    pub fn remove_from(&self, attributes: &mut Vec<ItemAttribute>) -> bool {
+        let len = attributes.len();
+        
+        if let Self::StrangePart(strange_part) = self {
+            // Each score type is paired with a score counter in the same slot.
+            let slots = StrangePart::DEFINDEX
+                .iter()
+                .zip(KillEaterUserScore::DEFINDEX.iter())
+                .filter(|(defindex, _)| {
+                    attributes.iter().any(|attribute| {
+                        attribute.defindex == **defindex &&
+                        strange_part_from_attribute(attribute) == Some(*strange_part)
+                    })
+                })
+                .flat_map(|(defindex, counter_defindex)| [*defindex, *counter_defindex])
+                .collect::<Vec<_>>();
+            
+            attributes.retain(|attribute| !slots.contains(&attribute.defindex));
+        } else {
+            let defindexes = self.attribute_defindexes();
+            
+            attributes.retain(|attribute| !defindexes.contains(&attribute.defindex));
+        }
+        
+        attributes.len() != len
+    }
+}
+
+/// The result of restoring an item.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::{Customization, ItemAttribute, Paint, Restoration, Spell};
+/// 
+/// let attributes = vec![
+///     ItemAttribute::from(Paint::PinkAsHell),
+///     ItemAttribute::from(Spell::Exorcism),
+/// ];
+/// let restoration = Restoration::from_attributes(&attributes);
+/// 
+/// assert_eq!(restoration.removed, vec![Customization::Paint(Some(Paint::PinkAsHell))]);
+/// // Spells are kept.
+/// assert_eq!(restoration.attributes.len(), 1);
+/// assert_eq!(restoration.attributes[0].defindex, Spell::DEFINDEX_EXORCISM);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Restoration {
+    /// The customizations that are removed. Each can be removed individually.
+    pub removed: Vec<Customization>,
+    /// The resulting attributes with all customizations removed.
+    pub attributes: Vec<ItemAttribute>,
+}
+
+impl Restoration {
+    /// Restores an item from its attributes. Attributes which are not customizations are kept in
+    /// their original order.
+    /// 
+    /// Paints and killstreaks are detected by their attributes' `defindex`es, so they are removed
+    /// even when their values are unknown. Strange parts with an unknown score type are not
+    /// considered customizations and are kept, as the score type identifies the part.
+    pub fn from_attributes(attributes: &[ItemAttribute]) -> Self {
+        let removed = customizations(attributes);
+        let mut attributes = attributes.to_vec();
+        
+        for customization in &removed {
+            customization.remove_from(&mut attributes);
+        }
+        
+        Self {
+            removed,
+            attributes,
+        }
+    }
+    
+    /// Checks if restoring the item would remove anything.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty()
+    }
+}
+
+/// Gets the customizations for a list of attributes.
+fn customizations(attributes: &[ItemAttribute]) -> Vec<Customization> {
+    let mut customizations = Vec::new();
+    
+    for attribute in attributes {
+        let customization = match attribute.defindex {
+            Paint::DEFINDEX |
+            SetItemTintRgb2::DEFINDEX => Some(Customization::Paint(paint(attributes))),
+            CustomNameAttr::DEFINDEX => Some(Customization::CustomName),
+            CustomDescAttr::DEFINDEX => Some(Customization::CustomDescription),
+            KillstreakTier::DEFINDEX |
+            Sheen::DEFINDEX |
+            Killstreaker::DEFINDEX => Some(Customization::Killstreak(killstreak_tier(attributes))),
+            IsFestivized::DEFINDEX => Some(Customization::Festivizer),
+            defindex if StrangePart::DEFINDEX.contains(&defindex) => {
+                strange_part_from_attribute(attribute)
+                    .map(Customization::StrangePart)
+            },
+            _ => None,
+        };
+        
+        if let Some(customization) = customization {
+            if !customizations.contains(&customization) {
+                customizations.push(customization);
+            }
+        }
+    }
+    
+    customizations
+}
+
+/// Gets the paint from the "set_item_tint_rgb" attribute, if it is known.
+fn paint(attributes: &[ItemAttribute]) -> Option<Paint> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.defindex == Paint::DEFINDEX)
+        .and_then(|attribute| attribute.float_value)
+        .and_then(Paint::try_from_attribute_float_value)
+}
+
+/// Gets the killstreak tier from the "killstreak_tier" attribute, if it is known.
+fn killstreak_tier(attributes: &[ItemAttribute]) -> Option<KillstreakTier> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.defindex == KillstreakTier::DEFINDEX)
+        .and_then(|attribute| attribute.float_value)
+        .and_then(KillstreakTier::try_from_attribute_float_value)
+}
+
+fn strange_part_from_attribute(attribute: &ItemAttribute) -> Option<StrangePart> {
+    attribute.float_value.and_then(StrangePart::try_from_attribute_float_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AttributeValue, Spell};
+    
+    #[test]
+    fn restores_item() {
+        let attributes = vec![
+            ItemAttribute::from(Paint::TeamSpirit),
+            ItemAttribute {
+                defindex: SetItemTintRgb2::DEFINDEX,
+                value: AttributeValue::from((0x5885A2 as f32).to_bits()),
+                float_value: Some(0x5885A2 as f32),
+            },
+            ItemAttribute::from(CustomNameAttr::from("Ol' Reliable")),
+            ItemAttribute::from(KillstreakTier::Professional),
+            ItemAttribute::from(Sheen::HotRod),
+            ItemAttribute::from(Killstreaker::Tornado),
+            ItemAttribute::from(Spell::HalloweenFire),
+            ItemAttribute::from(Spell::TeamSpiritFootprints),
+            // Paint spell, not a paint.
+            ItemAttribute::from(Spell::DieJob),
+            ItemAttribute {
+                defindex: 380,
+                value: AttributeValue::from(1117388800),
+                float_value: Some(77.0),
+            },
+            ItemAttribute {
+                defindex: 379,
+                value: AttributeValue::from(12),
+                float_value: None,
+            },
+            ItemAttribute {
+                defindex: 382,
+                value: AttributeValue::from(1108344832),
+                float_value: Some(34.0),
+            },
+            ItemAttribute {
+                defindex: 381,
+                value: AttributeValue::from(5),
+                float_value: None,
+            },
+        ];
+        let restoration = Restoration::from_attributes(&attributes);
+        
+        assert_eq!(restoration.removed, vec![
+            Customization::Paint(Some(Paint::TeamSpirit)),
+            Customization::CustomName,
+            Customization::Killstreak(Some(KillstreakTier::Professional)),
+            Customization::StrangePart(StrangePart::TauntKills),
+            Customization::StrangePart(StrangePart::KillsWhileExplosiveJumping),
+        ]);
+        
+        let defindexes = restoration.attributes
+            .iter()
+            .map(|attribute| attribute.defindex)
+            .collect::<Vec<_>>();
+        
+        assert_eq!(defindexes, vec![
+            Spell::DEFINDEX_HALLOWEEN_FIRE,
+            Spell::DEFINDEX_FOOTPRINTS,
+            Spell::DEFINDEX_PAINT,
+        ]);
+    }
+    
+    #[test]
+    fn removes_single_strange_part() {
+        let mut attributes = vec![
+            ItemAttribute {
+                defindex: 380,
+                value: AttributeValue::from(1117388800),
+                float_value: Some(77.0),
+            },
+            ItemAttribute {
+                defindex: 379,
+                value: AttributeValue::from(12),
+                float_value: None,
+            },
+            ItemAttribute {
+                defindex: 382,
+                value: AttributeValue::from(1108344832),
+                float_value: Some(34.0),
+            },
+            ItemAttribute {
+                defindex: 381,
+                value: AttributeValue::from(5),
+                float_value: None,
+            },
+        ];
+        
+        assert!(Customization::StrangePart(StrangePart::KillsWhileExplosiveJumping).remove_from(&mut attributes));
+        assert!(!Customization::StrangePart(StrangePart::CriticalKills).remove_from(&mut attributes));
+        assert_eq!(attributes.len(), 2);
+        assert_eq!(attributes[0].defindex, 380);
+        assert_eq!(attributes[1].defindex, 379);
+    }
+    
+    #[test]
+    fn removes_unknown_paint_and_killstreak() {
+        let attributes = vec![
+            ItemAttribute {
+                defindex: Paint::DEFINDEX,
+                value: AttributeValue::from((0x123456 as f32).to_bits()),
+                float_value: Some(0x123456 as f32),
+            },
+            ItemAttribute {
+                defindex: SetItemTintRgb2::DEFINDEX,
+                value: AttributeValue::from((0x654321 as f32).to_bits()),
+                float_value: Some(0x654321 as f32),
+            },
+            ItemAttribute::from(Sheen::HotRod),
+            ItemAttribute::from(Killstreaker::Tornado),
+            ItemAttribute::from(Spell::Exorcism),
+        ];
+        let restoration = Restoration::from_attributes(&attributes);
+        
+        assert_eq!(restoration.removed, vec![
+            Customization::Paint(None),
+            Customization::Killstreak(None),
+        ]);
+        assert_eq!(restoration.attributes.len(), 1);
+        assert_eq!(restoration.attributes[0].defindex, Spell::DEFINDEX_EXORCISM);
+    }
+    
+    #[test]
+    fn nothing_to_restore() {
+        let attributes = vec![ItemAttribute::from(Spell::Exorcism)];
+        let restoration = Restoration::from_attributes(&attributes);
+        
+        assert!(restoration.is_empty());
+        assert_eq!(restoration.attributes.len(), 1);
+    }
+}
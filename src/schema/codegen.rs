@@ -0,0 +1,1057 @@
+//! Regenerates the tables of enums which mirror values in the schema.
+//! 
+//! Tables are written between `// codegen:start <region>` and `// codegen:end <region>` markers in
+//! the crate's source files, listed in [`FILES`]. Existing variants keep their names, comments, and
+//! attributes, so manual aliases such as `serialize = "Ubers"` are preserved. When the schema
+//! renames a value, the new name becomes the variant's `to_string` and the previous names are kept
+//! as `serialize` aliases. Variants which are no longer in the schema are kept.
+//! 
+//! The `tf2-enum-codegen` binary runs this against the crate's `src` directory:
+//! ```sh
+//! cargo run --features schema --bin tf2-enum-codegen -- schema.json src
+//! ```
+
+use super::Schema;
+use crate::error::CodegenError;
+use std::collections::HashMap;
+use std::path::Path;
+
+const KILL_EATER_SCORE_TYPE: &str = "kill_eater_score_type.rs";
+const ITEM_LEVEL: &str = "item_level.rs";
+const ORIGIN: &str = "origin.rs";
+const QUALITY: &str = "quality.rs";
+const STRANGE_PART: &str = "strange_part.rs";
+
+/// The source files which contain generated tables, relative to the `src` directory.
+pub const FILES: &[&str] = &[
+    KILL_EATER_SCORE_TYPE,
+    ITEM_LEVEL,
+    ORIGIN,
+    QUALITY,
+    STRANGE_PART,
+];
+
+/// The name of the attribute which links a strange part item to its kill eater score type.
+const STRANGE_PART_COUNTER_ATTRIBUTE: &str = "strange part new counter ID";
+
+/// A regenerated source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// The name of the file, relative to the `src` directory.
+    pub file_name: &'static str,
+    /// The contents of the file.
+    pub contents: String,
+    /// Whether the contents differ from the source.
+    pub changed: bool,
+}
+
+/// Regenerates source files from a [`Schema`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::schema::Schema;
+/// use tf2_enum::schema::codegen::Codegen;
+/// 
+/// let schema = Schema::from_json_str(r#"{ "originNames": [] }"#).unwrap();
+/// let files = Codegen::new(&schema).generate_dir("src").unwrap();
+/// 
+/// // An empty schema leaves every table as it is.
+/// assert!(files.iter().all(|file| !file.changed));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Codegen<'a> {
+    schema: &'a Schema,
+}
+
+impl<'a> Codegen<'a> {
+    /// Creates a generator for a schema.
+    pub fn new(schema: &'a Schema) -> Self {
+        Self {
+            schema,
+        }
+    }
+    
+    /// Regenerates the tables in `sources`, a map of each file in [`FILES`] to its contents.
+    pub fn generate(
+        &self,
+        sources: &HashMap<String, String>,
+    ) -> Result<Vec<GeneratedFile>, CodegenError> {
+        let source = |file_name: &'static str| {
+            sources
+                .get(file_name)
+                .map(|contents| SourceFile::new(file_name, contents))
+                .ok_or_else(|| CodegenError::MissingFile(file_name.to_string()))
+        };
+        let mut kill_eater_score_type = source(KILL_EATER_SCORE_TYPE)?;
+        let mut item_level = source(ITEM_LEVEL)?;
+        let mut origin = source(ORIGIN)?;
+        let mut quality = source(QUALITY)?;
+        let mut strange_part = source(STRANGE_PART)?;
+        let score_types = merge_repr_variants(
+            parse_variants(&kill_eater_score_type.region("variants")?),
+            self.schema
+                .kill_eater_score_types()
+                .iter()
+                .map(|score_type| (score_type.id, score_type.type_name.as_str())),
+        );
+        
+        kill_eater_score_type.replace("variants", render_variants(&score_types))?;
+        self.item_level(&mut item_level, &score_types)?;
+        origin.replace("variants", render_variants(&merge_repr_variants(
+            parse_variants(&origin.region("variants")?),
+            self.schema
+                .origins()
+                .iter()
+                .map(|origin| (origin.origin, origin.name.as_str())),
+        )))?;
+        quality.replace("variants", render_variants(&merge_repr_variants(
+            parse_variants(&quality.region("variants")?),
+            self.schema
+                .qualities()
+                .iter()
+                .map(|quality| (quality.id, quality.display_name.as_str())),
+        )))?;
+        self.strange_part(&mut strange_part, &score_types)?;
+        
+        Ok([kill_eater_score_type, item_level, origin, quality, strange_part]
+            .into_iter()
+            .map(SourceFile::into_generated)
+            .collect())
+    }
+    
+    /// Regenerates the tables of the files in a source directory, e.g. "src". Files are not
+    /// written.
+    pub fn generate_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<GeneratedFile>, CodegenError> {
+        let dir = dir.as_ref();
+        let sources = FILES
+            .iter()
+            .map(|file_name| Ok((file_name.to_string(), std::fs::read_to_string(dir.join(file_name))?)))
+            .collect::<Result<HashMap<_, _>, CodegenError>>()?;
+        
+        self.generate(&sources)
+    }
+    
+    /// Regenerates the tables of the files in a source directory and writes the files which
+    /// changed. Returns the files which changed.
+    pub fn write_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<GeneratedFile>, CodegenError> {
+        let dir = dir.as_ref();
+        let mut files = self.generate_dir(dir)?;
+        
+        files.retain(|file| file.changed);
+        
+        for file in &files {
+            std::fs::write(dir.join(file.file_name), &file.contents)?;
+        }
+        
+        Ok(files)
+    }
+    
+    fn item_level(
+        &self,
+        file: &mut SourceFile<'_>,
+        score_types: &[Variant],
+    ) -> Result<(), CodegenError> {
+        let mut variants = parse_variants(&file.region("variants")?);
+        
+        for item_level in self.schema.item_levels() {
+            if !variants.iter().any(|variant| variant.has_name(&item_level.name)) {
+                let name = unique_ident(&variants, &item_level.name, None);
+                
+                variants.push(Variant::named(name, &item_level.name));
+            }
+        }
+        
+        let existing_levels = parse_level_arms(&file.region("levels")?);
+        let indent = file.indent("levels")?;
+        let mut levels = Vec::new();
+        
+        for variant in &variants {
+            let schema_levels = self.schema
+                .item_levels()
+                .iter()
+                .find(|item_level| variant.has_name(&item_level.name));
+            
+            if let Some(item_level) = schema_levels {
+                levels.push(format!("{indent}Self::{} => &[", variant.name));
+                
+                for level in &item_level.levels {
+                    levels.push(format!(
+                        "{indent}    Level {{ level: {}, required_score: {}, name: {:?} }},",
+                        level.level,
+                        level.required_score,
+                        level.name,
+                    ));
+                }
+                
+                levels.push(format!("{indent}],"));
+            } else if let Some(arm) = existing_levels.get(&variant.name) {
+                levels.extend(arm.iter().cloned());
+            } else {
+                return Err(CodegenError::MissingValue(variant.name.clone()));
+            }
+        }
+        
+        let existing_score_type_levels = parse_score_type_level_arms(&file.region("score_type_levels")?);
+        let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+        
+        for score_type in score_types {
+            let level_data = score_type.value
+                .and_then(|id| self.schema.kill_eater_score_type(id))
+                .map(|score_type| score_type.level_data.as_str());
+            let item_level = if let Some(level_data) = level_data {
+                variants
+                    .iter()
+                    .find(|variant| variant.has_name(level_data))
+                    .map(|variant| variant.name.as_str())
+                    .ok_or_else(|| CodegenError::UnknownItemLevel(level_data.to_string()))?
+            } else {
+                existing_score_type_levels
+                    .get(&score_type.name)
+                    .map(String::as_str)
+                    .ok_or_else(|| CodegenError::UnknownItemLevel(score_type.name.clone()))?
+            };
+            
+            if let Some((_, names)) = groups.iter_mut().find(|(name, _)| *name == item_level) {
+                names.push(&score_type.name);
+            } else {
+                groups.push((item_level, vec![&score_type.name]));
+            }
+        }
+        
+        let indent = file.indent("score_type_levels")?;
+        let mut score_type_levels = Vec::new();
+        
+        for (item_level, names) in groups {
+            let (last, rest) = names.split_last().expect("groups are never empty");
+            
+            for name in rest {
+                score_type_levels.push(format!("{indent}KillEaterScoreType::{name} |"));
+            }
+            
+            score_type_levels.push(format!("{indent}KillEaterScoreType::{last} => Self::{item_level},"));
+        }
+        
+        file.replace("variants", render_variants(&variants))?;
+        file.replace("levels", levels)?;
+        file.replace("score_type_levels", score_type_levels)
+    }
+    
+    fn strange_part(
+        &self,
+        file: &mut SourceFile<'_>,
+        score_types: &[Variant],
+    ) -> Result<(), CodegenError> {
+        let mut variants = parse_variants(&file.region("variants")?);
+        let item_names = parse_consts(&file.region("names")?);
+        let consts = parse_arms(&file.region("strange_part_name")?);
+        let defindexes = parse_arms(&file.region("defindex")?);
+        let mut parts = variants
+            .iter()
+            .map(|variant| {
+                let missing = || CodegenError::MissingValue(variant.name.clone());
+                let const_name = consts.get(&variant.name).ok_or_else(missing)?.clone();
+                let item_name = item_names.get(&const_name).ok_or_else(missing)?.clone();
+                let defindex = defindexes
+                    .get(&variant.name)
+                    .and_then(|defindex| defindex.parse::<u32>().ok())
+                    .ok_or_else(missing)?;
+                
+                Ok((variant.name.clone(), StrangePartTable {
+                    const_name,
+                    item_name,
+                    defindex,
+                }))
+            })
+            .collect::<Result<HashMap<_, _>, CodegenError>>()?;
+        
+        for (score_type, item_name, defindex) in strange_parts(self.schema) {
+            let type_name = self.schema
+                .kill_eater_score_type(score_type)
+                .map(|score_type| score_type.type_name.clone());
+            let name = if let Some(variant) = variants.iter_mut().find(|variant| variant.value == Some(score_type)) {
+                if let Some(type_name) = &type_name {
+                    variant.rename(type_name);
+                }
+                
+                variant.name.clone()
+            } else {
+                let score_type_variant = score_types
+                    .iter()
+                    .find(|variant| variant.value == Some(score_type))
+                    .ok_or_else(|| CodegenError::MissingValue(score_type.to_string()))?;
+                let type_name = type_name.unwrap_or_else(|| score_type_variant.preferred_name().to_string());
+                let name = unique_ident(&variants, &score_type_variant.name, Some(score_type));
+                
+                variants.push(Variant::repr(name.clone(), score_type, &type_name));
+                name
+            };
+            let table = parts.entry(name.clone()).or_insert_with(|| StrangePartTable {
+                const_name: format!("STR_{}", screaming_snake_case(&name)),
+                item_name: String::new(),
+                defindex,
+            });
+            
+            table.item_name = item_name.to_string();
+            table.defindex = defindex;
+        }
+        
+        variants.sort_by_key(|variant| variant.value);
+        
+        let tables = variants
+            .iter()
+            .map(|variant| (variant.name.as_str(), &parts[&variant.name]))
+            .collect::<Vec<_>>();
+        let render = |region: &'static str, f: &dyn Fn(&str, &StrangePartTable) -> String| {
+            let indent = file.indent(region)?;
+            
+            Ok::<_, CodegenError>(tables
+                .iter()
+                .map(|(name, table)| format!("{indent}{}", f(name, table)))
+                .collect::<Vec<_>>())
+        };
+        let names = render("names", &|_, table| {
+            format!("const {}: &str = {:?};", table.const_name, table.item_name)
+        })?;
+        let strange_part_name = render("strange_part_name", &|name, table| {
+            format!("Self::{name} => {},", table.const_name)
+        })?;
+        let from_strange_part_name = render("from_strange_part_name", &|name, table| {
+            format!("{} => Some(Self::{name}),", table.const_name)
+        })?;
+        let defindex = render("defindex", &|name, table| {
+            format!("Self::{name} => {},", table.defindex)
+        })?;
+        let from_defindex = render("from_defindex", &|name, table| {
+            format!("{} => Some(Self::{name}),", table.defindex)
+        })?;
+        
+        file.replace("names", names)?;
+        file.replace("variants", render_variants(&variants))?;
+        file.replace("strange_part_name", strange_part_name)?;
+        file.replace("from_strange_part_name", from_strange_part_name)?;
+        file.replace("defindex", defindex)?;
+        file.replace("from_defindex", from_defindex)
+    }
+}
+
+/// The score type, item name, and defindex of each strange part item in the schema. If multiple
+/// items share a score type, the one with the lowest defindex is used.
+pub(super) fn strange_parts(schema: &Schema) -> Vec<(u32, &str, u32)> {
+    let mut parts = schema
+        .items()
+        .iter()
+        .filter_map(|item| {
+            let score_type = item.attributes
+                .iter()
+                .find(|attribute| attribute.name == STRANGE_PART_COUNTER_ATTRIBUTE)?
+                .value as u32;
+            
+            Some((score_type, item.item_name.as_str(), item.defindex))
+        })
+        .collect::<Vec<_>>();
+    
+    parts.sort_by_key(|(score_type, _, defindex)| (*score_type, *defindex));
+    parts.dedup_by_key(|(score_type, _, _)| *score_type);
+    parts
+}
+
+struct StrangePartTable {
+    const_name: String,
+    item_name: String,
+    defindex: u32,
+}
+
+/// A source file with generated regions.
+struct SourceFile<'s> {
+    file_name: &'static str,
+    original: &'s str,
+    contents: String,
+}
+
+impl<'s> SourceFile<'s> {
+    fn new(file_name: &'static str, contents: &'s str) -> Self {
+        Self {
+            file_name,
+            original: contents,
+            contents: contents.to_string(),
+        }
+    }
+    
+    /// Finds the line indexes of the markers for a region.
+    fn markers(&self, region: &'static str) -> Result<(usize, usize), CodegenError> {
+        let start = format!("// codegen:start {region}");
+        let end = format!("// codegen:end {region}");
+        let lines = self.contents.lines().collect::<Vec<_>>();
+        let start = lines.iter().position(|line| line.trim() == start);
+        let end = lines.iter().position(|line| line.trim() == end);
+        
+        match (start, end) {
+            (Some(start), Some(end)) if start < end => Ok((start, end)),
+            _ => Err(CodegenError::MissingRegion {
+                file: self.file_name.to_string(),
+                region,
+            }),
+        }
+    }
+    
+    /// The lines within a region.
+    fn region(&self, region: &'static str) -> Result<Vec<String>, CodegenError> {
+        let (start, end) = self.markers(region)?;
+        
+        Ok(self.contents
+            .lines()
+            .skip(start + 1)
+            .take(end - start - 1)
+            .map(String::from)
+            .collect())
+    }
+    
+    /// The indentation of a region's markers.
+    fn indent(&self, region: &'static str) -> Result<String, CodegenError> {
+        let (start, _) = self.markers(region)?;
+        let line = self.contents.lines().nth(start).unwrap_or_default();
+        
+        Ok(line[..line.len() - line.trim_start().len()].to_string())
+    }
+    
+    /// Replaces the lines within a region.
+    fn replace(&mut self, region: &'static str, replacement: Vec<String>) -> Result<(), CodegenError> {
+        let (start, end) = self.markers(region)?;
+        let lines = self.contents.lines().collect::<Vec<_>>();
+        let mut contents = lines[..=start].join("\n");
+        
+        for line in replacement {
+            contents.push('\n');
+            contents.push_str(&line);
+        }
+        
+        contents.push('\n');
+        contents.push_str(&lines[end..].join("\n"));
+        
+        if self.contents.ends_with('\n') {
+            contents.push('\n');
+        }
+        
+        self.contents = contents;
+        Ok(())
+    }
+    
+    fn into_generated(self) -> GeneratedFile {
+        GeneratedFile {
+            file_name: self.file_name,
+            changed: self.contents != self.original,
+            contents: self.contents,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Line {
+    /// A comment or attribute, kept as written.
+    Verbatim(String),
+    /// The `#[strum(to_string = ..., serialize = ...)]` attribute, rendered from the variant's
+    /// names.
+    Serialize,
+}
+
+/// An enum variant along with the lines before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Variant {
+    lines: Vec<Line>,
+    name: String,
+    value: Option<u32>,
+    /// The name the variant is formatted as, if set with `to_string`.
+    to_string: Option<String>,
+    serializations: Vec<String>,
+    indent: String,
+}
+
+impl Variant {
+    /// Creates a variant with a discriminant.
+    fn repr(name: String, value: u32, serialization: &str) -> Self {
+        let lines = if name == serialization {
+            Vec::new()
+        } else {
+            vec![Line::Serialize]
+        };
+        
+        Self {
+            lines,
+            name,
+            value: Some(value),
+            to_string: None,
+            serializations: vec![serialization.to_string()],
+            indent: String::from("    "),
+        }
+    }
+    
+    /// Creates a variant which is serialized by name with both strum and serde.
+    fn named(name: String, serialization: &str) -> Self {
+        Self {
+            lines: vec![
+                Line::Serialize,
+                Line::Verbatim(format!("#[serde(rename = {serialization:?})]")),
+            ],
+            name,
+            value: None,
+            to_string: None,
+            serializations: vec![serialization.to_string()],
+            indent: String::from("    "),
+        }
+    }
+    
+    /// The names the variant is parsed from. Without a `to_string` or `serialize` attribute this
+    /// is the name of the variant.
+    fn names(&self) -> Vec<&str> {
+        if self.to_string.is_none() && self.serializations.is_empty() {
+            vec![self.name.as_str()]
+        } else {
+            self.to_string
+                .iter()
+                .chain(&self.serializations)
+                .map(String::as_str)
+                .collect()
+        }
+    }
+    
+    fn has_name(&self, name: &str) -> bool {
+        self.names().contains(&name)
+    }
+    
+    /// The name the variant is formatted as. strum uses `to_string` if it is set, otherwise the
+    /// longest serialization.
+    fn preferred_name(&self) -> &str {
+        if let Some(to_string) = &self.to_string {
+            return to_string;
+        }
+        
+        self.names().into_iter().rev().max_by_key(|name| name.len()).unwrap_or(&self.name)
+    }
+    
+    /// Formats the variant as `name`, keeping its other names as aliases.
+    fn rename(&mut self, name: &str) {
+        if self.preferred_name() == name {
+            return;
+        }
+        
+        let serializations = self.names()
+            .into_iter()
+            .filter(|alias| *alias != name)
+            .map(String::from)
+            .collect();
+        
+        self.to_string = Some(name.to_string());
+        self.serializations = serializations;
+        
+        if !self.lines.contains(&Line::Serialize) {
+            let position = self.lines
+                .iter()
+                .position(|line| matches!(line, Line::Verbatim(line) if line.starts_with("#[")))
+                .unwrap_or(self.lines.len());
+            
+            self.lines.insert(position, Line::Serialize);
+        }
+    }
+    
+    fn render(&self, lines: &mut Vec<String>) {
+        let indent = &self.indent;
+        
+        for line in &self.lines {
+            match line {
+                Line::Verbatim(line) => lines.push(format!("{indent}{line}")),
+                Line::Serialize => {
+                    let names = self.to_string
+                        .iter()
+                        .map(|name| format!("to_string = {name:?}"))
+                        .chain(self.serializations.iter().map(|name| format!("serialize = {name:?}")))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    
+                    lines.push(format!("{indent}#[strum({names})]"));
+                },
+            }
+        }
+        
+        match self.value {
+            Some(value) => lines.push(format!("{indent}{} = {value},", self.name)),
+            None => lines.push(format!("{indent}{},", self.name)),
+        }
+    }
+}
+
+fn indent_of(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Parses the variants of an enum body.
+fn parse_variants(lines: &[String]) -> Vec<Variant> {
+    let mut variants = Vec::new();
+    let mut pending = Vec::new();
+    let mut to_string = None;
+    let mut serializations = Vec::new();
+    
+    for line in lines {
+        let trimmed = line.trim();
+        
+        if trimmed.is_empty() {
+            continue;
+        }
+        
+        if trimmed.starts_with("#[strum(serialize") || trimmed.starts_with("#[strum(to_string") {
+            for (key, name) in quoted_strings_with_keys(trimmed) {
+                if key == "to_string" {
+                    to_string = Some(name);
+                } else {
+                    serializations.push(name);
+                }
+            }
+            
+            pending.push(Line::Serialize);
+        } else if trimmed.starts_with("//") || trimmed.starts_with("#[") {
+            pending.push(Line::Verbatim(trimmed.to_string()));
+        } else {
+            let declaration = trimmed.trim_end_matches(',');
+            let (name, value) = match declaration.split_once('=') {
+                Some((name, value)) => (name.trim(), value.trim().parse().ok()),
+                None => (declaration, None),
+            };
+            
+            variants.push(Variant {
+                lines: std::mem::take(&mut pending),
+                name: name.to_string(),
+                value,
+                to_string: to_string.take(),
+                serializations: std::mem::take(&mut serializations),
+                indent: indent_of(line).to_string(),
+            });
+        }
+    }
+    
+    variants
+}
+
+/// Merges values from the schema into the variants of an enum with discriminants. Variants are
+/// ordered by their discriminant.
+fn merge_repr_variants<'n, I>(mut variants: Vec<Variant>, values: I) -> Vec<Variant>
+where
+    I: Iterator<Item = (u32, &'n str)>,
+{
+    for (value, name) in values {
+        if let Some(variant) = variants.iter_mut().find(|variant| variant.value == Some(value)) {
+            variant.rename(name);
+        } else {
+            let ident = unique_ident(&variants, name, Some(value));
+            
+            variants.push(Variant::repr(ident, value, name));
+        }
+    }
+    
+    variants.sort_by_key(|variant| variant.value);
+    variants
+}
+
+fn render_variants(variants: &[Variant]) -> Vec<String> {
+    let mut lines = Vec::new();
+    
+    for variant in variants {
+        variant.render(&mut lines);
+    }
+    
+    lines
+}
+
+/// Parses the arms of `ItemLevel::levels`, keyed by variant.
+fn parse_level_arms(lines: &[String]) -> HashMap<String, Vec<String>> {
+    let mut arms = HashMap::new();
+    let mut current: Option<(String, Vec<String>)> = None;
+    
+    for line in lines {
+        let trimmed = line.trim();
+        
+        if let Some(name) = trimmed.strip_prefix("Self::").and_then(|rest| rest.strip_suffix(" => &[")) {
+            current = Some((name.to_string(), Vec::new()));
+        }
+        
+        if let Some((_, arm)) = &mut current {
+            arm.push(line.clone());
+        }
+        
+        if trimmed == "]," {
+            if let Some((name, arm)) = current.take() {
+                arms.insert(name, arm);
+            }
+        }
+    }
+    
+    arms
+}
+
+/// Parses the arms of `From<KillEaterScoreType> for ItemLevel`, keyed by score type.
+fn parse_score_type_level_arms(lines: &[String]) -> HashMap<String, String> {
+    let mut arms = HashMap::new();
+    let mut pending = Vec::new();
+    
+    for line in lines {
+        let Some(rest) = line.trim().strip_prefix("KillEaterScoreType::") else {
+            continue;
+        };
+        
+        if let Some((name, item_level)) = rest.split_once(" => Self::") {
+            pending.push(name.to_string());
+            
+            for name in pending.drain(..) {
+                arms.insert(name, item_level.trim_end_matches(',').to_string());
+            }
+        } else {
+            pending.push(rest.trim_end_matches('|').trim().to_string());
+        }
+    }
+    
+    arms
+}
+
+/// Parses match arms in the form `Self::Variant => value,`, keyed by variant.
+fn parse_arms(lines: &[String]) -> HashMap<String, String> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let (name, value) = line.trim().strip_prefix("Self::")?.split_once(" => ")?;
+            
+            Some((name.to_string(), value.trim_end_matches(',').to_string()))
+        })
+        .collect()
+}
+
+/// Parses string constants in the form `const NAME: &str = "value";`, keyed by name.
+fn parse_consts(lines: &[String]) -> HashMap<String, String> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let (name, _) = line.trim().strip_prefix("const ")?.split_once(':')?;
+            let value = quoted_strings(line).into_iter().next()?;
+            
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Reads the string literals in a line.
+fn quoted_strings(line: &str) -> Vec<String> {
+    quoted_strings_with_keys(line).into_iter().map(|(_, string)| string).collect()
+}
+
+/// Parses the quoted strings in a line along with the identifier before each of them, e.g.
+/// `("serialize", "Ubers")` for `serialize = "Ubers"`.
+fn quoted_strings_with_keys(line: &str) -> Vec<(String, String)> {
+    let mut strings = Vec::new();
+    let mut chars = line.chars();
+    
+    loop {
+        let mut prefix = String::new();
+        let mut opened = false;
+        
+        for c in chars.by_ref() {
+            if c == '"' {
+                opened = true;
+                break;
+            }
+            
+            prefix.push(c);
+        }
+        
+        if !opened {
+            break;
+        }
+        
+        let key = prefix
+            .trim_end_matches(|c: char| c == '=' || c.is_whitespace())
+            .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let mut string = String::new();
+        
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => match chars.next() {
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    Some(c) => string.push(c),
+                    None => {},
+                },
+                c => string.push(c),
+            }
+        }
+        
+        strings.push((key, string));
+    }
+    
+    strings
+}
+
+/// Converts a name from the schema into a variant name, e.g. "Kills While Explosive-Jumping" into
+/// "KillsWhileExplosiveJumping".
+fn ident(name: &str) -> String {
+    let mut ident = String::new();
+    
+    for word in name.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = word.chars().map(|c| match c {
+            'À' | 'Á' | 'Â' | 'Ä' => 'A',
+            'à' | 'á' | 'â' | 'ä' => 'a',
+            'È' | 'É' | 'Ê' | 'Ë' => 'E',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'Ò' | 'Ó' | 'Ô' | 'Ö' => 'O',
+            'ò' | 'ó' | 'ô' | 'ö' => 'o',
+            'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            c => c,
+        });
+        
+        if let Some(first) = chars.next() {
+            ident.extend(first.to_uppercase());
+            ident.extend(chars);
+        }
+    }
+    
+    ident.retain(|c| c.is_ascii_alphanumeric());
+    
+    if !ident.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        ident.insert_str(0, "Value");
+    }
+    
+    ident
+}
+
+/// Creates a variant name which does not conflict with an existing variant.
+fn unique_ident(variants: &[Variant], name: &str, value: Option<u32>) -> String {
+    let ident = ident(name);
+    
+    if !variants.iter().any(|variant| variant.name == ident) {
+        return ident;
+    }
+    
+    match value {
+        Some(value) => format!("{ident}{value}"),
+        None => format!("{ident}{}", variants.len()),
+    }
+}
+
+/// Converts a variant name into the name of a constant, e.g. "KillsUnderAFullMoon" into
+/// "KILLS_UNDER_A_FULL_MOON".
+fn screaming_snake_case(name: &str) -> String {
+    let mut output = String::new();
+    
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            output.push('_');
+        }
+        
+        output.push(c.to_ascii_uppercase());
+    }
+    
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        HasItemDefindex,
+        IntoEnumIterator,
+        ItemLevel,
+        KillEaterScoreType,
+        Origin,
+        Quality,
+        StrangePart,
+    };
+    use serde_json::json;
+    
+    fn sources() -> HashMap<String, String> {
+        [
+            (KILL_EATER_SCORE_TYPE, include_str!("../kill_eater_score_type.rs")),
+            (ITEM_LEVEL, include_str!("../item_level.rs")),
+            (ORIGIN, include_str!("../origin.rs")),
+            (QUALITY, include_str!("../quality.rs")),
+            (STRANGE_PART, include_str!("../strange_part.rs")),
+        ]
+            .into_iter()
+            .map(|(file_name, contents)| (file_name.to_string(), contents.to_string()))
+            .collect()
+    }
+    
+    /// A schema containing the values currently defined by this crate.
+    fn current_schema() -> Schema {
+        let json = json!({
+            "qualities": Quality::iter()
+                .map(|quality| (format!("quality{}", quality as u32), quality as u32))
+                .collect::<HashMap<_, _>>(),
+            "qualityNames": Quality::iter()
+                .map(|quality| (format!("quality{}", quality as u32), quality.to_string()))
+                .collect::<HashMap<_, _>>(),
+            "originNames": Origin::iter()
+                .map(|origin| json!({ "origin": origin as u32, "name": origin.to_string() }))
+                .collect::<Vec<_>>(),
+            "item_levels": ItemLevel::iter()
+                .map(|item_level| json!({
+                    "name": item_level.to_string(),
+                    "levels": item_level.levels()
+                        .iter()
+                        .map(|level| json!({
+                            "level": level.level,
+                            "required_score": level.required_score,
+                            "name": level.name,
+                        }))
+                        .collect::<Vec<_>>(),
+                }))
+                .collect::<Vec<_>>(),
+            "kill_eater_score_types": KillEaterScoreType::iter()
+                .map(|score_type| json!({
+                    "type": score_type as u32,
+                    "type_name": score_type.to_string(),
+                    "level_data": score_type.item_level().to_string(),
+                }))
+                .collect::<Vec<_>>(),
+            "items": StrangePart::iter()
+                .map(|part| json!({
+                    "defindex": part.defindex(),
+                    "name": part.strange_part_name(),
+                    "item_name": part.strange_part_name(),
+                    "attributes": [
+                        { "name": STRANGE_PART_COUNTER_ATTRIBUTE, "value": part as u32 },
+                    ],
+                }))
+                .collect::<Vec<_>>(),
+        });
+        
+        Schema::from_json_str(&json.to_string()).unwrap()
+    }
+    
+    fn generated(files: &[GeneratedFile], file_name: &str) -> String {
+        files.iter().find(|file| file.file_name == file_name).unwrap().contents.clone()
+    }
+    
+    /// An origin table whose formatted names differ from the variant names.
+    const ORIGIN_FIXTURE: &str = r#"pub enum Origin {
+    // codegen:start variants
+    #[strum(serialize = "Timed Drop")]
+    TimedDrop = 0,
+    #[strum(serialize = "Store", serialize = "Purchased")]
+    Purchased = 2,
+    #[strum(to_string = "Trade", serialize = "Traded")]
+    Traded = 3,
+    // codegen:end variants
+}
+"#;
+    
+    fn generate_origins(origins: serde_json::Value) -> GeneratedFile {
+        let schema = Schema::from_json_str(&json!({ "originNames": origins }).to_string()).unwrap();
+        let mut sources = sources();
+        
+        sources.insert(ORIGIN.to_string(), ORIGIN_FIXTURE.to_string());
+        
+        Codegen::new(&schema)
+            .generate(&sources)
+            .unwrap()
+            .into_iter()
+            .find(|file| file.file_name == ORIGIN)
+            .unwrap()
+    }
+    
+    #[test]
+    fn matching_names_are_unchanged() {
+        let file = generate_origins(json!([
+            { "origin": 0, "name": "Timed Drop" },
+            { "origin": 2, "name": "Purchased" },
+            { "origin": 3, "name": "Trade" },
+        ]));
+        
+        assert_eq!(file.contents, ORIGIN_FIXTURE);
+        assert!(!file.changed);
+    }
+    
+    #[test]
+    fn renames_to_shorter_names() {
+        let file = generate_origins(json!([
+            { "origin": 2, "name": "Store" },
+            { "origin": 3, "name": "Traded" },
+        ]));
+        
+        assert!(file.changed);
+        assert!(file.contents.contains("    #[strum(to_string = \"Store\", serialize = \"Purchased\")]\n    Purchased = 2,\n"));
+        assert!(file.contents.contains("    #[strum(to_string = \"Traded\", serialize = \"Trade\")]\n    Traded = 3,\n"));
+        
+        let lines = file.contents.lines().map(String::from).collect::<Vec<_>>();
+        let variants = parse_variants(&lines[2..lines.len() - 2]);
+        
+        assert_eq!(variants[1].preferred_name(), "Store");
+        assert!(variants[1].has_name("Purchased"));
+        assert_eq!(variants[2].preferred_name(), "Traded");
+    }
+    
+    #[test]
+    fn adds_and_renames_values() {
+        let mut schema = current_schema();
+        
+        schema.merge(Schema::from_json_str(&json!({
+            "originNames": [
+                { "origin": 3, "name": "Trade" },
+                { "origin": 99, "name": "Some New Origin" },
+            ],
+            "kill_eater_score_types": [
+                { "type": 200, "type_name": "Über Kills", "level_data": "KillEater_NewRank" },
+            ],
+            "item_levels": [
+                { "name": "KillEater_NewRank", "levels": [{ "level": 0, "required_score": 1, "name": "New" }] },
+            ],
+            "items": [
+                {
+                    "defindex": 6100,
+                    "name": "Strange Part: Über Kills",
+                    "item_name": "Strange Part: Über Kills",
+                    "attributes": [{ "name": STRANGE_PART_COUNTER_ATTRIBUTE, "value": 200 }],
+                },
+            ],
+        }).to_string()).unwrap());
+        
+        let files = Codegen::new(&schema).generate(&sources()).unwrap();
+        let origin = generated(&files, ORIGIN);
+        let kill_eater_score_type = generated(&files, KILL_EATER_SCORE_TYPE);
+        let item_level = generated(&files, ITEM_LEVEL);
+        let strange_part = generated(&files, STRANGE_PART);
+        
+        assert!(origin.contains("    #[strum(to_string = \"Trade\", serialize = \"Traded\")]\n    Traded = 3,\n"));
+        assert!(origin.contains("    #[strum(serialize = \"Some New Origin\")]\n    SomeNewOrigin = 99,\n"));
+        // Manual aliases are kept.
+        assert!(kill_eater_score_type.contains("#[strum(serialize = \"Ubers\", serialize = \"Übers\")]"));
+        assert!(kill_eater_score_type.contains("    #[strum(serialize = \"Über Kills\")]\n    UberKills = 200,\n"));
+        assert!(item_level.contains("    #[serde(rename = \"KillEater_NewRank\")]\n    KillEaterNewRank,\n"));
+        assert!(item_level.contains("                Level { level: 0, required_score: 1, name: \"New\" },\n"));
+        assert!(item_level.contains("            KillEaterScoreType::UberKills => Self::KillEaterNewRank,\n"));
+        assert!(strange_part.contains("const STR_UBER_KILLS: &str = \"Strange Part: Über Kills\";\n"));
+        assert!(strange_part.contains("            6100 => Some(Self::UberKills),\n"));
+        // Other values are unchanged.
+        assert!(strange_part.contains("            Self::ScoutsKilled => 6003,\n"));
+    }
+    
+    #[test]
+    fn missing_region() {
+        let schema = Schema::default();
+        let mut sources = sources();
+        
+        sources.insert(ORIGIN.to_string(), String::from("pub enum Origin {}\n"));
+        
+        assert!(matches!(
+            Codegen::new(&schema).generate(&sources),
+            Err(CodegenError::MissingRegion { region: "variants", .. }),
+        ));
+    }
+    
+    #[test]
+    fn converts_names() {
+        assert_eq!(ident("Kills While Explosive-Jumping"), "KillsWhileExplosiveJumping");
+        assert_eq!(ident("KillEater_HolidayPunchRank"), "KillEaterHolidayPunchRank");
+        assert_eq!(ident("Medics Killed That Have Full ÜberCharge"), "MedicsKilledThatHaveFullUberCharge");
+        assert_eq!(screaming_snake_case("KillsUnderAFullMoon"), "KILLS_UNDER_A_FULL_MOON");
+    }
+}
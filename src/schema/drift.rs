@@ -0,0 +1,887 @@
+//! Compares a schema with the values compiled into this crate.
+//! 
+//! Unlike [`codegen`][`super::codegen`], nothing is written. The report lists values which are new
+//! in the schema, strings which were renamed, attribute definitions which changed, and item
+//! defindexes which moved. Values which are defined by this crate but are missing from the schema
+//! are not reported, except for attributes and stock weapons. Sections which are empty in the
+//! schema are skipped, so the overview and the items should be merged into one schema before
+//! checking.
+//! 
+//! The `tf2-enum-drift` binary runs this against a schema dump:
+//! ```sh
+//! cargo run --features schema --bin tf2-enum-drift -- schema.json
+//! ```
+
+use super::{Schema, SchemaLevel};
+use super::codegen::strange_parts;
+use crate::{
+    Attribute,
+    AttributeDef,
+    Attributes,
+    FootprintsSpell,
+    HasItemDefindex,
+    IntoEnumIterator,
+    ItemLevel,
+    KillEaterScoreType,
+    Killstreaker,
+    KillstreakTier,
+    Level,
+    Origin,
+    Paint,
+    PaintSpell,
+    Quality,
+    Sheen,
+    Spell,
+    StockWeapon,
+    StrangePart,
+    Wear,
+};
+use crate::econ_attributes::*;
+use std::fmt;
+use std::str::FromStr;
+
+/// The prefixes of the internal names of stock weapon items.
+const STOCK_WEAPON_PREFIXES: &[&str] = &["TF_WEAPON_", "Upgradeable TF_WEAPON_"];
+
+/// A difference between the schema and this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Drift {
+    /// A quality which is not defined by [`Quality`].
+    NewQuality {
+        /// The ID of the quality.
+        id: u32,
+        /// The localized name of the quality.
+        name: String,
+    },
+    /// A quality whose localized name is not one of the names of its variant.
+    RenamedQuality {
+        /// The quality.
+        quality: Quality,
+        /// The name in the schema.
+        name: String,
+    },
+    /// An origin which is not defined by [`Origin`].
+    NewOrigin {
+        /// The ID of the origin.
+        id: u32,
+        /// The name of the origin.
+        name: String,
+    },
+    /// An origin whose name is not one of the names of its variant.
+    RenamedOrigin {
+        /// The origin.
+        origin: Origin,
+        /// The name in the schema.
+        name: String,
+    },
+    /// A score type which is not defined by [`KillEaterScoreType`].
+    NewKillEaterScoreType {
+        /// The ID of the score type.
+        id: u32,
+        /// The name of the score type.
+        name: String,
+    },
+    /// A score type whose name differs from the name it is formatted as.
+    RenamedKillEaterScoreType {
+        /// The score type.
+        score_type: KillEaterScoreType,
+        /// The name in the schema.
+        name: String,
+    },
+    /// A score type which uses a different set of item levels than
+    /// [`KillEaterScoreType::item_level`].
+    ChangedKillEaterScoreTypeItemLevel {
+        /// The score type.
+        score_type: KillEaterScoreType,
+        /// The name of the item level set in the schema.
+        level_data: String,
+    },
+    /// An item level set which is not defined by [`ItemLevel`].
+    NewItemLevel {
+        /// The name of the item level set.
+        name: String,
+    },
+    /// A level which was added, removed, or changed in an item level set.
+    ChangedLevel {
+        /// The item level set.
+        item_level: ItemLevel,
+        /// The level.
+        level: u32,
+        /// The level defined by [`ItemLevel::levels`], if any.
+        expected: Option<Level>,
+        /// The level in the schema, if any.
+        actual: Option<SchemaLevel>,
+    },
+    /// An attribute defined by this crate which is not in the schema.
+    MissingAttribute {
+        /// The defindex of the attribute.
+        defindex: u32,
+        /// The name of the attribute.
+        name: &'static str,
+    },
+    /// A field of an [`AttributeDef`] which differs from the schema. Values are formatted using
+    /// their [`Debug`][`fmt::Debug`] representation.
+    ChangedAttribute {
+        /// The defindex of the attribute.
+        defindex: u32,
+        /// The name of the field, e.g. "description_string".
+        field: &'static str,
+        /// The value defined by this crate.
+        expected: String,
+        /// The value in the schema.
+        actual: String,
+    },
+    /// A strange part whose score type is not defined by [`StrangePart`].
+    NewStrangePart {
+        /// The score type of the strange part.
+        score_type: u32,
+        /// The name of the strange part item.
+        name: String,
+        /// The defindex of the strange part item.
+        defindex: u32,
+    },
+    /// A strange part whose item name differs from [`StrangePart::strange_part_name`].
+    RenamedStrangePart {
+        /// The strange part.
+        strange_part: StrangePart,
+        /// The name of the item in the schema.
+        name: String,
+    },
+    /// A strange part whose item defindex differs from [`StrangePart::defindex`].
+    ChangedStrangePartDefindex {
+        /// The strange part.
+        strange_part: StrangePart,
+        /// The defindex of the item in the schema.
+        defindex: u32,
+    },
+    /// A stock weapon whose items differ from [`StockWeapon::defindexes`].
+    ChangedStockWeaponDefindexes {
+        /// The stock weapon.
+        stock_weapon: StockWeapon,
+        /// The defindexes of the items in the schema.
+        defindexes: Vec<u32>,
+    },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NewQuality { id, name } => write!(f, "new quality {id}: {name:?}"),
+            Self::RenamedQuality { quality, name } => {
+                write!(f, "quality {} renamed from {:?} to {name:?}", *quality as u32, quality.to_string())
+            },
+            Self::NewOrigin { id, name } => write!(f, "new origin {id}: {name:?}"),
+            Self::RenamedOrigin { origin, name } => {
+                write!(f, "origin {} renamed from {:?} to {name:?}", *origin as u32, origin.to_string())
+            },
+            Self::NewKillEaterScoreType { id, name } => {
+                write!(f, "new kill eater score type {id}: {name:?}")
+            },
+            Self::RenamedKillEaterScoreType { score_type, name } => write!(
+                f,
+                "kill eater score type {} renamed from {:?} to {name:?}",
+                *score_type as u32,
+                score_type.to_string(),
+            ),
+            Self::ChangedKillEaterScoreTypeItemLevel { score_type, level_data } => write!(
+                f,
+                "kill eater score type {} item level changed from {:?} to {level_data:?}",
+                *score_type as u32,
+                score_type.item_level().to_string(),
+            ),
+            Self::NewItemLevel { name } => write!(f, "new item level {name:?}"),
+            Self::ChangedLevel { item_level, level, expected, actual } => {
+                write!(f, "item level {:?} level {level} ", item_level.to_string())?;
+                
+                match (expected, actual) {
+                    (Some(expected), Some(actual)) => write!(
+                        f,
+                        "changed from {:?} at {} to {:?} at {}",
+                        expected.name,
+                        expected.required_score,
+                        actual.name,
+                        actual.required_score,
+                    ),
+                    (None, Some(actual)) => {
+                        write!(f, "added: {:?} at {}", actual.name, actual.required_score)
+                    },
+                    _ => write!(f, "removed"),
+                }
+            },
+            Self::MissingAttribute { defindex, name } => {
+                write!(f, "attribute {defindex} ({name:?}) is not in the schema")
+            },
+            Self::ChangedAttribute { defindex, field, expected, actual } => {
+                write!(f, "attribute {defindex} {field} changed from {expected} to {actual}")
+            },
+            Self::NewStrangePart { score_type, name, defindex } => {
+                write!(f, "new strange part {score_type}: {name:?} ({defindex})")
+            },
+            Self::RenamedStrangePart { strange_part, name } => write!(
+                f,
+                "strange part {} renamed from {:?} to {name:?}",
+                *strange_part as u32,
+                strange_part.strange_part_name(),
+            ),
+            Self::ChangedStrangePartDefindex { strange_part, defindex } => write!(
+                f,
+                "strange part {:?} defindex changed from {} to {defindex}",
+                strange_part.strange_part_name(),
+                strange_part.defindex(),
+            ),
+            Self::ChangedStockWeaponDefindexes { stock_weapon, defindexes } => write!(
+                f,
+                "stock weapon {:?} defindexes changed from {:?} to {defindexes:?}",
+                stock_weapon.to_string(),
+                stock_weapon.defindexes(),
+            ),
+        }
+    }
+}
+
+/// The differences between a schema and the values compiled into this crate.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::schema::Schema;
+/// use tf2_enum::schema::drift::{Drift, DriftReport};
+/// 
+/// let schema = Schema::from_json_str(r#"{
+///     "qualities": { "Normal": 0, "rarity1": 1, "new_quality": 99 },
+///     "qualityNames": { "Normal": "Normal", "rarity1": "Genuine", "new_quality": "Shiny" }
+/// }"#).unwrap();
+/// let report = DriftReport::check(&schema);
+/// 
+/// assert_eq!(report.drift(), &[Drift::NewQuality { id: 99, name: "Shiny".into() }]);
+/// assert_eq!(report.to_string(), "new quality 99: \"Shiny\"\n");
+/// ```
+/// 
+/// In a test suite, fail with the report if anything differs:
+/// ```no_run
+/// use tf2_enum::schema::Schema;
+/// use tf2_enum::schema::drift::DriftReport;
+/// 
+/// let schema = Schema::from_path("schema.json").unwrap();
+/// let report = DriftReport::check(&schema);
+/// 
+/// assert!(report.is_empty(), "{report}");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriftReport {
+    drift: Vec<Drift>,
+}
+
+impl DriftReport {
+    /// Compares a schema with the values compiled into this crate.
+    pub fn check(schema: &Schema) -> Self {
+        let mut report = Self::default();
+        
+        report.qualities(schema);
+        report.origins(schema);
+        report.kill_eater_score_types(schema);
+        report.item_levels(schema);
+        report.attributes(schema);
+        report.strange_parts(schema);
+        report.stock_weapons(schema);
+        report
+    }
+    
+    /// The differences which were found.
+    pub fn drift(&self) -> &[Drift] {
+        &self.drift
+    }
+    
+    /// Returns `true` if the schema matches this crate.
+    pub fn is_empty(&self) -> bool {
+        self.drift.is_empty()
+    }
+    
+    /// The number of differences which were found.
+    pub fn len(&self) -> usize {
+        self.drift.len()
+    }
+    
+    /// Iterates over the differences.
+    pub fn iter(&self) -> std::slice::Iter<'_, Drift> {
+        self.drift.iter()
+    }
+    
+    fn qualities(&mut self, schema: &Schema) {
+        for schema_quality in schema.qualities() {
+            let name = &schema_quality.display_name;
+            
+            match schema_quality.quality() {
+                None => self.drift.push(Drift::NewQuality {
+                    id: schema_quality.id,
+                    name: name.clone(),
+                }),
+                Some(quality) if Quality::from_str(name).ok() != Some(quality) => {
+                    self.drift.push(Drift::RenamedQuality {
+                        quality,
+                        name: name.clone(),
+                    });
+                },
+                _ => {},
+            }
+        }
+    }
+    
+    fn origins(&mut self, schema: &Schema) {
+        for schema_origin in schema.origins() {
+            let name = &schema_origin.name;
+            
+            match schema_origin.origin() {
+                None => self.drift.push(Drift::NewOrigin {
+                    id: schema_origin.origin,
+                    name: name.clone(),
+                }),
+                Some(origin) if Origin::from_str(name).ok() != Some(origin) => {
+                    self.drift.push(Drift::RenamedOrigin {
+                        origin,
+                        name: name.clone(),
+                    });
+                },
+                _ => {},
+            }
+        }
+    }
+    
+    fn kill_eater_score_types(&mut self, schema: &Schema) {
+        for schema_score_type in schema.kill_eater_score_types() {
+            let name = &schema_score_type.type_name;
+            let Some(score_type) = schema_score_type.kill_eater_score_type() else {
+                self.drift.push(Drift::NewKillEaterScoreType {
+                    id: schema_score_type.id,
+                    name: name.clone(),
+                });
+                continue;
+            };
+            
+            if score_type.to_string() != *name {
+                self.drift.push(Drift::RenamedKillEaterScoreType {
+                    score_type,
+                    name: name.clone(),
+                });
+            }
+            
+            if ItemLevel::from_str(&schema_score_type.level_data).ok() != Some(score_type.item_level()) {
+                self.drift.push(Drift::ChangedKillEaterScoreTypeItemLevel {
+                    score_type,
+                    level_data: schema_score_type.level_data.clone(),
+                });
+            }
+        }
+    }
+    
+    fn item_levels(&mut self, schema: &Schema) {
+        for schema_item_level in schema.item_levels() {
+            let Some(item_level) = schema_item_level.item_level() else {
+                self.drift.push(Drift::NewItemLevel {
+                    name: schema_item_level.name.clone(),
+                });
+                continue;
+            };
+            let mut levels = item_level.levels()
+                .iter()
+                .map(|level| level.level)
+                .chain(schema_item_level.levels.iter().map(|level| level.level))
+                .collect::<Vec<_>>();
+            
+            levels.sort_unstable();
+            levels.dedup();
+            
+            for level in levels {
+                let expected = item_level.levels()
+                    .iter()
+                    .find(|expected| expected.level == level)
+                    .copied();
+                let actual = schema_item_level.levels
+                    .iter()
+                    .find(|actual| actual.level == level)
+                    .cloned();
+                let unchanged = match (&expected, &actual) {
+                    (Some(expected), Some(actual)) => {
+                        expected.name == actual.name &&
+                        expected.required_score == actual.required_score
+                    },
+                    _ => false,
+                };
+                
+                if !unchanged {
+                    self.drift.push(Drift::ChangedLevel {
+                        item_level,
+                        level,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+    
+    fn attributes(&mut self, schema: &Schema) {
+        if schema.attributes().is_empty() {
+            return;
+        }
+        
+        for attribute in attribute_defs() {
+            let Some(schema_attribute) = schema.attribute(attribute.defindex) else {
+                self.drift.push(Drift::MissingAttribute {
+                    defindex: attribute.defindex,
+                    name: attribute.name,
+                });
+                continue;
+            };
+            let mut compare = |field: &'static str, expected: String, actual: String| {
+                if expected != actual {
+                    self.drift.push(Drift::ChangedAttribute {
+                        defindex: attribute.defindex,
+                        field,
+                        expected,
+                        actual,
+                    });
+                }
+            };
+            
+            compare(
+                "name",
+                format!("{:?}", attribute.name),
+                format!("{:?}", schema_attribute.name),
+            );
+            compare(
+                "attribute_class",
+                format!("{:?}", attribute.attribute_class),
+                format!("{:?}", schema_attribute.attribute_class.as_deref()),
+            );
+            compare(
+                "description_string",
+                format!("{:?}", attribute.description_string),
+                format!("{:?}", schema_attribute.description_string.as_deref()),
+            );
+            compare(
+                "description_format",
+                format!("{:?}", attribute.description_format),
+                format!("{:?}", schema_attribute.description_format),
+            );
+            compare(
+                "effect_type",
+                format!("{:?}", Some(attribute.effect_type)),
+                format!("{:?}", schema_attribute.effect_type),
+            );
+            compare(
+                "hidden",
+                attribute.hidden.to_string(),
+                schema_attribute.hidden.to_string(),
+            );
+            compare(
+                "stored_as_integer",
+                attribute.stored_as_integer.to_string(),
+                schema_attribute.stored_as_integer.to_string(),
+            );
+        }
+    }
+    
+    fn strange_parts(&mut self, schema: &Schema) {
+        for (score_type, name, defindex) in strange_parts(schema) {
+            let Ok(strange_part) = StrangePart::try_from(score_type) else {
+                self.drift.push(Drift::NewStrangePart {
+                    score_type,
+                    name: name.to_string(),
+                    defindex,
+                });
+                continue;
+            };
+            
+            if strange_part.strange_part_name() != name {
+                self.drift.push(Drift::RenamedStrangePart {
+                    strange_part,
+                    name: name.to_string(),
+                });
+            }
+            
+            if strange_part.defindex() != defindex {
+                self.drift.push(Drift::ChangedStrangePartDefindex {
+                    strange_part,
+                    defindex,
+                });
+            }
+        }
+    }
+    
+    /// Stock weapons are matched to the stock items in the schema which share an item name with
+    /// any of their defindexes.
+    fn stock_weapons(&mut self, schema: &Schema) {
+        if schema.items().is_empty() {
+            return;
+        }
+        
+        for stock_weapon in StockWeapon::iter() {
+            let item_names = stock_weapon.defindexes()
+                .iter()
+                .filter_map(|defindex| schema.item(*defindex))
+                .map(|item| item.item_name.as_str())
+                .collect::<Vec<_>>();
+            let mut defindexes = schema
+                .items()
+                .iter()
+                .filter(|item| {
+                    item_names.contains(&item.item_name.as_str()) &&
+                    STOCK_WEAPON_PREFIXES.iter().any(|prefix| item.name.starts_with(prefix))
+                })
+                .map(|item| item.defindex)
+                .collect::<Vec<_>>();
+            let mut expected = stock_weapon.defindexes().to_vec();
+            
+            defindexes.sort_unstable();
+            expected.sort_unstable();
+            
+            if defindexes != expected {
+                self.drift.push(Drift::ChangedStockWeaponDefindexes {
+                    stock_weapon,
+                    defindexes,
+                });
+            }
+        }
+    }
+}
+
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for drift in &self.drift {
+            writeln!(f, "{drift}")?;
+        }
+        
+        Ok(())
+    }
+}
+
+impl IntoIterator for DriftReport {
+    type Item = Drift;
+    type IntoIter = std::vec::IntoIter<Drift>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.drift.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a DriftReport {
+    type Item = &'a Drift;
+    type IntoIter = std::slice::Iter<'a, Drift>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.drift.iter()
+    }
+}
+
+/// Every attribute definition compiled into this crate, ordered by defindex. Definitions which
+/// are repeated across types are included once.
+fn attribute_defs() -> Vec<AttributeDef> {
+    macro_rules! defs {
+        (
+            attribute: [$($attribute:ty),* $(,)?],
+            attributes: [$($attributes:ty),* $(,)?] $(,)?
+        ) => {{
+            let mut defs = vec![$(<$attribute as Attribute>::ATTRIBUTE),*];
+            
+            $(defs.extend_from_slice(<$attributes as Attributes>::ATTRIBUTES);)*
+            defs
+        }};
+    }
+    
+    let mut defs = defs! {
+        attribute: [
+            FootprintsSpell,
+            Killstreaker,
+            KillstreakTier,
+            Paint,
+            PaintSpell,
+            Sheen,
+            Wear,
+            CannotTrade,
+            AlwaysTradable,
+            NeverCraftable,
+            NonEconomy,
+            HalloweenVoiceModulation,
+            HalloweenPumpkinExplosions,
+            HalloweenGreenFlames,
+            HalloweenDeathGhosts,
+            IsAustralium,
+            IsFestivized,
+            KillEater,
+            TauntAttachParticleIndex,
+            SetAttachedParticle,
+            PaintkitProtoDefIndex,
+            CustomPaintkitSeedLo,
+            CustomPaintkitSeedHi,
+            ToolTargetItem,
+            SetItemTintRgb2,
+            SupplyCrateSeries,
+            SeriesNumber,
+            UniqueCraftIndex,
+            GifterAccountId,
+            MakersMarkId,
+            EventDate,
+            TradableAfterDate,
+            ExpirationDate,
+            CustomTextureLo,
+            CustomTextureHi,
+            DynamicRecipeComponentDefinedItem1,
+            DynamicRecipeComponentDefinedItem2,
+            DynamicRecipeComponentDefinedItem3,
+            DynamicRecipeComponentDefinedItem4,
+            DynamicRecipeComponentDefinedItem5,
+            DynamicRecipeComponentDefinedItem6,
+            DynamicRecipeComponentDefinedItem7,
+            DynamicRecipeComponentDefinedItem8,
+            DynamicRecipeComponentDefinedItem9,
+            DynamicRecipeComponentDefinedItem10,
+            CustomNameAttr,
+            CustomDescAttr,
+        ],
+        attributes: [
+            KillEaterScoreType,
+            Spell,
+            StrangePart,
+            DynamicRecipeComponentDefinedItem,
+            KillEaterScore,
+            KillEaterUserScore,
+        ],
+    };
+    
+    let mut unique = Vec::with_capacity(defs.len());
+    
+    defs.sort_by_key(|def| def.defindex);
+    
+    for def in defs {
+        if !unique.contains(&def) {
+            unique.push(def);
+        }
+    }
+    
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    
+    /// The schema containing the values currently defined by this crate, as JSON.
+    fn current_json() -> Value {
+        let mut items = StrangePart::iter()
+            .map(|part| json!({
+                "defindex": part.defindex(),
+                "name": part.strange_part_name(),
+                "item_name": part.strange_part_name(),
+                "attributes": [
+                    { "name": "strange part new counter ID", "value": part as u32 },
+                ],
+            }))
+            .collect::<Vec<_>>();
+        
+        for stock_weapon in StockWeapon::iter() {
+            for defindex in stock_weapon.defindexes() {
+                items.push(json!({
+                    "defindex": defindex,
+                    "name": format!("TF_WEAPON_{defindex}"),
+                    "item_name": stock_weapon.to_string(),
+                }));
+            }
+        }
+        
+        json!({
+            "qualities": Quality::iter()
+                .map(|quality| (format!("quality{}", quality as u32), json!(quality as u32)))
+                .collect::<serde_json::Map<_, _>>(),
+            "qualityNames": Quality::iter()
+                .map(|quality| (format!("quality{}", quality as u32), json!(quality.to_string())))
+                .collect::<serde_json::Map<_, _>>(),
+            "originNames": Origin::iter()
+                .map(|origin| json!({ "origin": origin as u32, "name": origin.to_string() }))
+                .collect::<Vec<_>>(),
+            "item_levels": ItemLevel::iter()
+                .map(|item_level| json!({
+                    "name": item_level.to_string(),
+                    "levels": item_level.levels()
+                        .iter()
+                        .map(|level| json!({
+                            "level": level.level,
+                            "required_score": level.required_score,
+                            "name": level.name,
+                        }))
+                        .collect::<Vec<_>>(),
+                }))
+                .collect::<Vec<_>>(),
+            "kill_eater_score_types": KillEaterScoreType::iter()
+                .map(|score_type| json!({
+                    "type": score_type as u32,
+                    "type_name": score_type.to_string(),
+                    "level_data": score_type.item_level().to_string(),
+                }))
+                .collect::<Vec<_>>(),
+            "attributes": attribute_defs()
+                .iter()
+                .map(|attribute| json!({
+                    "defindex": attribute.defindex,
+                    "name": attribute.name,
+                    "attribute_class": attribute.attribute_class,
+                    "description_string": attribute.description_string,
+                    "description_format": attribute.description_format,
+                    "effect_type": attribute.effect_type,
+                    "hidden": attribute.hidden,
+                    "stored_as_integer": attribute.stored_as_integer,
+                }))
+                .collect::<Vec<_>>(),
+            "items": items,
+        })
+    }
+    
+    fn check(json: &Value) -> DriftReport {
+        DriftReport::check(&Schema::from_json_str(&json.to_string()).unwrap())
+    }
+    
+    #[test]
+    fn current_schema_has_no_drift() {
+        let report = check(&current_json());
+        
+        assert!(report.is_empty(), "{report}");
+    }
+    
+    #[test]
+    fn empty_schema_has_no_drift() {
+        assert!(DriftReport::check(&Schema::default()).is_empty());
+    }
+    
+    #[test]
+    fn reports_new_and_renamed_values() {
+        let mut json = current_json();
+        
+        json["qualities"]["quality99"] = json!(99);
+        json["qualityNames"]["quality99"] = json!("Shiny");
+        json["originNames"][2]["name"] = json!("Bought");
+        json["kill_eater_score_types"].as_array_mut().unwrap().push(json!({
+            "type": 999,
+            "type_name": "Things Done",
+            "level_data": "KillEaterRank",
+        }));
+        json["item_levels"].as_array_mut().unwrap().push(json!({ "name": "NewRank", "levels": [] }));
+        
+        let report = check(&json);
+        
+        assert!(report.drift().contains(&Drift::NewQuality { id: 99, name: "Shiny".into() }));
+        assert!(report.drift().contains(&Drift::RenamedOrigin {
+            origin: Origin::Purchased,
+            name: "Bought".into(),
+        }));
+        assert!(report.drift().contains(&Drift::NewKillEaterScoreType {
+            id: 999,
+            name: "Things Done".into(),
+        }));
+        assert!(report.drift().contains(&Drift::NewItemLevel { name: "NewRank".into() }));
+        assert_eq!(report.len(), 4, "{report}");
+        assert!(report.to_string().contains("origin 2 renamed from \"Purchased\" to \"Bought\"\n"));
+    }
+    
+    #[test]
+    fn reports_changed_levels() {
+        let mut json = current_json();
+        let item_level = json["item_levels"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|item_level| item_level["name"] == "KillEaterRank")
+            .unwrap();
+        
+        item_level["levels"][0]["name"] = json!("Unusual");
+        item_level["levels"].as_array_mut().unwrap().pop();
+        
+        let report = check(&json);
+        let last = ItemLevel::KillEaterRank.levels().last().unwrap();
+        
+        assert_eq!(report.drift(), &[
+            Drift::ChangedLevel {
+                item_level: ItemLevel::KillEaterRank,
+                level: 0,
+                expected: Some(ItemLevel::KillEaterRank.levels()[0]),
+                actual: Some(SchemaLevel {
+                    level: 0,
+                    required_score: ItemLevel::KillEaterRank.levels()[0].required_score,
+                    name: "Unusual".into(),
+                }),
+            },
+            Drift::ChangedLevel {
+                item_level: ItemLevel::KillEaterRank,
+                level: last.level,
+                expected: Some(*last),
+                actual: None,
+            },
+        ]);
+    }
+    
+    #[test]
+    fn reports_changed_attributes() {
+        let mut json = current_json();
+        let attributes = json["attributes"].as_array_mut().unwrap();
+        let attribute = attributes
+            .iter_mut()
+            .find(|attribute| attribute["defindex"] == Paint::DEFINDEX)
+            .unwrap();
+        
+        attribute["hidden"] = json!(!Paint::ATTRIBUTE.hidden);
+        attribute["description_string"] = json!("Painted %s1");
+        attributes.retain(|attribute| attribute["defindex"] != Sheen::DEFINDEX);
+        
+        let report = check(&json);
+        
+        assert_eq!(report.drift(), &[
+            Drift::ChangedAttribute {
+                defindex: Paint::DEFINDEX,
+                field: "description_string",
+                expected: format!("{:?}", Paint::ATTRIBUTE.description_string),
+                actual: "Some(\"Painted %s1\")".into(),
+            },
+            Drift::ChangedAttribute {
+                defindex: Paint::DEFINDEX,
+                field: "hidden",
+                expected: Paint::ATTRIBUTE.hidden.to_string(),
+                actual: (!Paint::ATTRIBUTE.hidden).to_string(),
+            },
+            Drift::MissingAttribute {
+                defindex: Sheen::DEFINDEX,
+                name: Sheen::ATTRIBUTE.name,
+            },
+        ]);
+    }
+    
+    #[test]
+    fn reports_changed_defindexes() {
+        let mut json = current_json();
+        let items = json["items"].as_array_mut().unwrap();
+        
+        for item in items.iter_mut() {
+            if item["defindex"] == StrangePart::Kills.defindex() {
+                item["defindex"] = json!(99999);
+            }
+        }
+        
+        items.push(json!({
+            "defindex": 30000,
+            "name": "Upgradeable TF_WEAPON_BAT",
+            "item_name": "Bat",
+        }));
+        
+        let report = check(&json);
+        
+        assert_eq!(report.drift(), &[
+            Drift::ChangedStrangePartDefindex {
+                strange_part: StrangePart::Kills,
+                defindex: 99999,
+            },
+            Drift::ChangedStockWeaponDefindexes {
+                stock_weapon: StockWeapon::Bat,
+                defindexes: vec![0, 190, 30000],
+            },
+        ]);
+    }
+}
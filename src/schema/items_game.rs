@@ -0,0 +1,667 @@
+//! Typed definitions loaded from `items_game.txt`.
+
+use super::SchemaAttribute;
+use crate::{
+    Capability,
+    Class,
+    Collection,
+    CraftClass,
+    CraftMaterialType,
+    DescriptionFormat,
+    DropType,
+    EffectType,
+    Grade,
+    HolidayRestriction,
+    ItemSlot,
+    PaintKit,
+    Quality,
+    Rarity,
+    IntoEnumIterator,
+};
+use crate::error::VdfError;
+use crate::vdf::{self, Object, ParseOptions, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use serde::Deserialize;
+
+/// The name of the static attribute which links a War Paint to its paint kit.
+const PAINTKIT_ATTRIBUTE_NAME: &str = "paintkit_proto_def_index";
+/// Prefabs can reference other prefabs. This guards against cycles.
+const MAX_PREFAB_DEPTH: usize = 16;
+
+/// An item definition from `items_game.txt`, with its prefabs applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemsGameItem {
+    /// The item's definition index.
+    pub defindex: u32,
+    /// The internal name of the item.
+    pub name: String,
+    /// The prefabs the item inherits from, in the order they are listed.
+    pub prefabs: Vec<String>,
+    /// The item's class, e.g. "tf_weapon_scattergun".
+    pub item_class: Option<String>,
+    /// The localization token for the item's name, e.g. "#TF_Unique_Achievement_Scattergun_Double".
+    pub item_name: Option<String>,
+    /// The localization token for the item's type name.
+    pub item_type_name: Option<String>,
+    /// The localization token for the item's description.
+    pub item_description: Option<String>,
+    /// The item slot the item is equipped in.
+    pub item_slot: Option<ItemSlot>,
+    /// The default quality of the item.
+    pub item_quality: Option<Quality>,
+    /// The craft class of the item.
+    pub craft_class: Option<CraftClass>,
+    /// The craft material type of the item.
+    pub craft_material_type: Option<CraftMaterialType>,
+    /// The drop type of the item.
+    pub drop_type: Option<DropType>,
+    /// The holiday the item is restricted to.
+    pub holiday_restriction: Option<HolidayRestriction>,
+    /// The classes which can use the item. Empty if the item is usable by all classes.
+    pub used_by_classes: Vec<Class>,
+    /// The capabilities of the item which are enabled.
+    pub capabilities: Vec<Capability>,
+    /// The equip regions the item occupies.
+    pub equip_regions: Vec<String>,
+    /// The attributes of the item.
+    pub attributes: Vec<ItemsGameItemAttribute>,
+    /// The static attributes of the item.
+    pub static_attrs: Vec<ItemsGameItemAttribute>,
+}
+
+impl ItemsGameItem {
+    /// Gets the paint kit this item applies, if it is a War Paint.
+    pub fn paint_kit_id(&self) -> Option<u32> {
+        self.static_attrs
+            .iter()
+            .find(|attribute| attribute.name == PAINTKIT_ATTRIBUTE_NAME)
+            .and_then(|attribute| attribute.value.parse().ok())
+    }
+}
+
+/// An attribute on an item definition from `items_game.txt`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ItemsGameItemAttribute {
+    /// The name of the attribute.
+    pub name: String,
+    /// The attribute class of the attribute.
+    pub attribute_class: Option<String>,
+    /// The value of the attribute, as written.
+    pub value: String,
+}
+
+/// A paint kit referenced by a War Paint in `items_game.txt`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PaintKitDef {
+    /// The paint kit's ID, as stored in the "paintkit_proto_def_index" attribute.
+    pub id: u32,
+    /// The internal name of the War Paint item which applies this paint kit.
+    pub name: String,
+    /// The definition index of the War Paint item which applies this paint kit.
+    pub item_defindex: u32,
+    /// The grade of the War Paint item, from the collection it belongs to.
+    pub grade: Option<Grade>,
+    /// The name of the collection the War Paint item belongs to.
+    pub collection: Option<String>,
+}
+
+/// Uses the internal name of the War Paint item as the name.
+impl From<&PaintKitDef> for PaintKit {
+    fn from(paint_kit: &PaintKitDef) -> Self {
+        Self {
+            id: paint_kit.id,
+            name: paint_kit.name.clone().into(),
+            grade: paint_kit.grade,
+            collection: paint_kit.collection.clone().map(Into::into),
+        }
+    }
+}
+
+/// Definitions loaded from `items_game.txt`.
+/// 
+/// # Examples
+/// ```
+/// use tf2_enum::schema::ItemsGame;
+/// use tf2_enum::{Class, ItemSlot, Quality};
+/// 
+/// let items_game = ItemsGame::parse(r#"
+///     "items_game"
+///     {
+///         "qualities" { "unique" { "value" "6" } }
+///         "prefabs"
+///         {
+///             "weapon_bat" { "item_slot" "melee" "used_by_classes" { "scout" "1" } }
+///         }
+///         "items"
+///         {
+///             "0"
+///             {
+///                 "name" "TF_WEAPON_BAT"
+///                 "prefab" "weapon_bat"
+///                 "item_quality" "unique"
+///             }
+///         }
+///     }
+/// "#).unwrap();
+/// let bat = items_game.item(0).unwrap();
+/// 
+/// assert_eq!(bat.item_slot, Some(ItemSlot::Melee));
+/// assert_eq!(bat.item_quality, Some(Quality::Unique));
+/// assert_eq!(bat.used_by_classes, vec![Class::Scout]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ItemsGame {
+    items: Vec<ItemsGameItem>,
+    attributes: Vec<SchemaAttribute>,
+    paint_kits: Vec<PaintKitDef>,
+    collections: Vec<Collection>,
+    items_by_defindex: HashMap<u32, usize>,
+    items_by_name: HashMap<String, usize>,
+    attributes_by_defindex: HashMap<u32, usize>,
+    attributes_by_name: HashMap<String, usize>,
+}
+
+impl ItemsGame {
+    /// Parses the contents of `items_game.txt` using the default
+    /// [`ParseOptions`][`crate::vdf::ParseOptions`].
+    pub fn parse(input: &str) -> Result<Self, VdfError> {
+        Self::from_object(&vdf::parse(input)?)
+    }
+    
+    /// Loads `items_game.txt` from a file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, VdfError> {
+        Self::from_object(&vdf::parse_file(path, &ParseOptions::default())?)
+    }
+    
+    /// Loads definitions from parsed KeyValues. The definitions may either be at the root of the
+    /// object or within an "items_game" key.
+    pub fn from_object(object: &Object) -> Result<Self, VdfError> {
+        let root = object.get_object("items_game").unwrap_or(object);
+        let qualities = root.get_object("qualities")
+            .map(qualities)
+            .unwrap_or_default();
+        let empty = Object::new();
+        let prefabs = root.get_object("prefabs").unwrap_or(&empty);
+        let mut items_game = Self::default();
+        
+        if let Some(items) = root.get_object("items") {
+            for (key, value) in items.iter() {
+                // Skips the "default" item and any other non-numeric keys.
+                let (Ok(defindex), Some(object)) = (key.parse::<u32>(), value.as_object()) else {
+                    continue;
+                };
+                let object = apply_prefabs(object, prefabs, 0);
+                let raw: RawItem = vdf::from_object(&object)?;
+                
+                items_game.items.push(raw.into_item(defindex, &qualities));
+            }
+        }
+        
+        if let Some(attributes) = root.get_object("attributes") {
+            for (key, value) in attributes.iter() {
+                let (Ok(defindex), Some(object)) = (key.parse::<u32>(), value.as_object()) else {
+                    continue;
+                };
+                let raw: RawAttribute = vdf::from_object(object)?;
+                
+                items_game.attributes.push(raw.into_attribute(defindex));
+            }
+        }
+        
+        for item in &items_game.items {
+            let Some(id) = item.paint_kit_id() else {
+                continue;
+            };
+            
+            if !items_game.paint_kits.iter().any(|paint_kit| paint_kit.id == id) {
+                items_game.paint_kits.push(PaintKitDef {
+                    id,
+                    name: item.name.clone(),
+                    item_defindex: item.defindex,
+                    grade: None,
+                    collection: None,
+                });
+            }
+        }
+        
+        items_game.index();
+        
+        if let Some(collections) = root.get_object("item_collections") {
+            for (key, value) in collections.iter() {
+                let Some(object) = value.as_object() else {
+                    continue;
+                };
+                let collection = items_game.collection_from_object(key, object);
+                
+                items_game.collections.push(collection);
+            }
+        }
+        
+        for paint_kit in &mut items_game.paint_kits {
+            let found = items_game.collections
+                .iter()
+                .find_map(|collection| Some((collection, collection.grade_of(paint_kit.item_defindex)?)));
+            
+            if let Some((collection, grade)) = found {
+                paint_kit.grade = Some(grade);
+                paint_kit.collection = Some(collection.name.to_string());
+            }
+        }
+        
+        Ok(items_game)
+    }
+    
+    /// Reads an item collection. Items are listed by name within their rarity, e.g. "common".
+    /// Items which are not defined are skipped.
+    fn collection_from_object(&self, name: &str, object: &Object) -> Collection {
+        let mut collection = Collection::new(name.to_string());
+        let Some(rarities) = object.get_object("items") else {
+            return collection;
+        };
+        
+        for (rarity, items) in rarities.iter() {
+            let rarity = Rarity::iter().find(|r| r.to_string().eq_ignore_ascii_case(rarity));
+            let (Some(rarity), Some(items)) = (rarity, items.as_object()) else {
+                continue;
+            };
+            
+            for (item_name, _) in items.iter() {
+                if let Some(item) = self.item_by_name(item_name) {
+                    collection.insert_rarity(rarity, item.defindex);
+                }
+            }
+        }
+        
+        collection
+    }
+    
+    fn index(&mut self) {
+        for (i, item) in self.items.iter().enumerate() {
+            self.items_by_defindex.insert(item.defindex, i);
+            self.items_by_name.entry(item.name.clone()).or_insert(i);
+        }
+        
+        for (i, attribute) in self.attributes.iter().enumerate() {
+            self.attributes_by_defindex.insert(attribute.defindex, i);
+            self.attributes_by_name.entry(attribute.name.clone()).or_insert(i);
+        }
+    }
+    
+    /// Gets all item definitions.
+    pub fn items(&self) -> &[ItemsGameItem] {
+        &self.items
+    }
+    
+    /// Gets an item definition by its defindex.
+    pub fn item(&self, defindex: u32) -> Option<&ItemsGameItem> {
+        self.items_by_defindex.get(&defindex).map(|i| &self.items[*i])
+    }
+    
+    /// Gets an item definition by its internal name. If multiple items share a name, the first is
+    /// returned.
+    pub fn item_by_name(&self, name: &str) -> Option<&ItemsGameItem> {
+        self.items_by_name.get(name).map(|i| &self.items[*i])
+    }
+    
+    /// Gets all attribute definitions.
+    pub fn attributes(&self) -> &[SchemaAttribute] {
+        &self.attributes
+    }
+    
+    /// Gets an attribute definition by its defindex.
+    pub fn attribute(&self, defindex: u32) -> Option<&SchemaAttribute> {
+        self.attributes_by_defindex.get(&defindex).map(|i| &self.attributes[*i])
+    }
+    
+    /// Gets an attribute definition by its name.
+    pub fn attribute_by_name(&self, name: &str) -> Option<&SchemaAttribute> {
+        self.attributes_by_name.get(name).map(|i| &self.attributes[*i])
+    }
+    
+    /// Gets all paint kits referenced by War Paints.
+    pub fn paint_kits(&self) -> &[PaintKitDef] {
+        &self.paint_kits
+    }
+    
+    /// Gets a paint kit by its ID.
+    pub fn paint_kit(&self, id: u32) -> Option<&PaintKitDef> {
+        self.paint_kits.iter().find(|paint_kit| paint_kit.id == id)
+    }
+    
+    /// Gets all item collections. Each collection is named by its key in the "item_collections"
+    /// section, e.g. "concealed_killer_collection".
+    pub fn collections(&self) -> &[Collection] {
+        &self.collections
+    }
+    
+    /// Gets an item collection by its name.
+    pub fn collection(&self, name: &str) -> Option<&Collection> {
+        self.collections.iter().find(|collection| collection.name == name)
+    }
+    
+    /// Gets the collection an item belongs to.
+    pub fn item_collection(&self, defindex: u32) -> Option<&Collection> {
+        self.collections.iter().find(|collection| collection.contains(defindex))
+    }
+}
+
+/// Applies an item's prefabs. The item's own values take precedence, followed by each prefab in
+/// the order they are listed.
+fn apply_prefabs(object: &Object, prefabs: &Object, depth: usize) -> Object {
+    let mut object = object.clone();
+    
+    if depth >= MAX_PREFAB_DEPTH {
+        return object;
+    }
+    
+    let names = object.get_str("prefab")
+        .map(|names| names.split_whitespace().map(String::from).collect::<Vec<_>>())
+        .unwrap_or_default();
+    
+    for name in names {
+        if let Some(prefab) = prefabs.get_object(&name) {
+            let prefab = apply_prefabs(prefab, prefabs, depth + 1);
+            
+            object.merge_missing(&prefab);
+        }
+    }
+    
+    object
+}
+
+/// Maps quality names to their values, e.g. "rarity1" to [`Quality::Genuine`].
+fn qualities(object: &Object) -> HashMap<String, Quality> {
+    object
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = value.as_object()?.get_str("value")?.parse::<u32>().ok()?;
+            let quality = Quality::try_from(value).ok()?;
+            
+            Some((name.to_ascii_lowercase(), quality))
+        })
+        .collect()
+}
+
+/// Parses a value, ignoring ASCII case.
+fn parse_ignore_case<T>(value: &str) -> Option<T>
+where
+    T: FromStr + IntoEnumIterator + fmt::Display,
+{
+    value.parse().ok().or_else(|| T::iter().find(|t| t.to_string().eq_ignore_ascii_case(value)))
+}
+
+/// Keys within an object which are enabled, i.e. have a value other than "0".
+fn enabled_keys<T>(map: &BTreeMap<String, Value>) -> Vec<T>
+where
+    T: FromStr + IntoEnumIterator + fmt::Display + Ord,
+{
+    let mut values = map
+        .iter()
+        .filter(|(_, value)| value.as_str() != Some("0"))
+        .filter_map(|(key, _)| parse_ignore_case(key))
+        .collect::<Vec<T>>();
+    
+    values.sort();
+    values.dedup();
+    values
+}
+
+fn item_attributes(map: BTreeMap<String, Value>) -> Vec<ItemsGameItemAttribute> {
+    map.into_iter()
+        .filter_map(|(name, value)| match value {
+            Value::String(value) => Some(ItemsGameItemAttribute {
+                name,
+                attribute_class: None,
+                value,
+            }),
+            Value::Object(object) => Some(ItemsGameItemAttribute {
+                name,
+                attribute_class: object.get_str("attribute_class").map(String::from),
+                value: object.get_str("value")?.to_string(),
+            }),
+        })
+        .collect()
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RawItem {
+    name: Option<String>,
+    prefab: Option<String>,
+    item_class: Option<String>,
+    item_name: Option<String>,
+    item_type_name: Option<String>,
+    item_description: Option<String>,
+    item_slot: Option<String>,
+    item_quality: Option<String>,
+    craft_class: Option<String>,
+    craft_material_type: Option<String>,
+    drop_type: Option<String>,
+    holiday_restriction: Option<String>,
+    equip_region: Option<String>,
+    equip_regions: BTreeMap<String, Value>,
+    used_by_classes: BTreeMap<String, Value>,
+    capabilities: BTreeMap<String, Value>,
+    attributes: BTreeMap<String, Value>,
+    static_attrs: BTreeMap<String, Value>,
+}
+
+impl RawItem {
+    fn into_item(self, defindex: u32, qualities: &HashMap<String, Quality>) -> ItemsGameItem {
+        let mut equip_regions = self.equip_region.into_iter().collect::<Vec<_>>();
+        
+        for region in self.equip_regions.into_keys() {
+            if !equip_regions.contains(&region) {
+                equip_regions.push(region);
+            }
+        }
+        
+        ItemsGameItem {
+            defindex,
+            name: self.name.unwrap_or_default(),
+            prefabs: self.prefab
+                .map(|prefab| prefab.split_whitespace().map(String::from).collect())
+                .unwrap_or_default(),
+            item_class: self.item_class,
+            item_name: self.item_name,
+            item_type_name: self.item_type_name,
+            item_description: self.item_description,
+            item_slot: self.item_slot.as_deref().and_then(parse_ignore_case),
+            item_quality: self.item_quality
+                .and_then(|quality| qualities.get(&quality.to_ascii_lowercase()).copied()),
+            craft_class: self.craft_class.as_deref().and_then(parse_ignore_case),
+            craft_material_type: self.craft_material_type.as_deref().and_then(parse_ignore_case),
+            drop_type: self.drop_type.as_deref().and_then(parse_ignore_case),
+            holiday_restriction: self.holiday_restriction.as_deref().and_then(parse_ignore_case),
+            used_by_classes: enabled_keys(&self.used_by_classes),
+            capabilities: enabled_keys(&self.capabilities),
+            equip_regions,
+            attributes: item_attributes(self.attributes),
+            static_attrs: item_attributes(self.static_attrs),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RawAttribute {
+    name: String,
+    attribute_class: Option<String>,
+    description_string: Option<String>,
+    description_format: Option<String>,
+    effect_type: Option<String>,
+    hidden: bool,
+    stored_as_integer: bool,
+}
+
+impl RawAttribute {
+    fn into_attribute(self, defindex: u32) -> SchemaAttribute {
+        // Effect types added in later updates are loaded as `None` rather than failing the load.
+        let effect_type = match self.effect_type {
+            Some(effect_type) => parse_ignore_case::<EffectType>(&effect_type),
+            None => Some(EffectType::Neutral),
+        };
+        
+        SchemaAttribute {
+            defindex,
+            name: self.name,
+            attribute_class: self.attribute_class,
+            description_string: self.description_string,
+            description_format: self.description_format
+                .as_deref()
+                .and_then(parse_ignore_case::<DescriptionFormat>),
+            effect_type,
+            hidden: self.hidden,
+            stored_as_integer: self.stored_as_integer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const ITEMS_GAME: &str = r##"
+        "items_game"
+        {
+            "qualities"
+            {
+                "normal" { "value" "0" }
+                "rarity1" { "value" "1" }
+                "unique" { "value" "6" }
+            }
+            "prefabs"
+            {
+                "base_hat"
+                {
+                    "item_slot" "misc"
+                    "craft_class" "hat"
+                    "capabilities" { "paintable" "1" "nameable" "1" "can_gift_wrap" "0" }
+                }
+                "valve" { "item_quality" "unique" "prefab" "base_hat" }
+                "paintkit_tool" { "item_class" "tool" "craft_class" "" }
+            }
+            "items"
+            {
+                "default" { "name" "default" }
+                "30000"
+                {
+                    "name" "Example Hat"
+                    "prefab" "valve"
+                    "item_name" "#TF_ExampleHat"
+                    "equip_region" "hat"
+                    "used_by_classes" { "heavy" "1" "demoman" "1" }
+                    "capabilities" { "nameable" "0" }
+                    "holiday_restriction" "halloween_or_fullmoon"
+                    "attributes"
+                    {
+                        "set item tint RGB" { "attribute_class" "set_item_tint_rgb" "value" "1315860" }
+                    }
+                }
+                "16000"
+                {
+                    "name" "Paintkit 200"
+                    "prefab" "paintkit_tool"
+                    "static_attrs" { "paintkit_proto_def_index" "200" }
+                }
+                "16001"
+                {
+                    "name" "Paintkit 200 Duplicate"
+                    "prefab" "paintkit_tool"
+                    "static_attrs" { "paintkit_proto_def_index" "200" }
+                }
+            }
+            "item_collections"
+            {
+                "example_collection"
+                {
+                    "name" "#Example_Collection"
+                    "items"
+                    {
+                        "mythical" { "Paintkit 200" "1" }
+                        "legendary" { "Example Hat" "1" "Missing Item" "1" }
+                    }
+                }
+            }
+            "attributes"
+            {
+                "142"
+                {
+                    "name" "set item tint RGB"
+                    "attribute_class" "set_item_tint_rgb"
+                    "description_format" "value_is_additive"
+                    "hidden" "1"
+                    "effect_type" "positive"
+                    "stored_as_integer" "0"
+                }
+            }
+        }
+    "##;
+    
+    #[test]
+    fn loads_items() {
+        let items_game = ItemsGame::parse(ITEMS_GAME).unwrap();
+        let hat = items_game.item(30000).unwrap();
+        
+        assert_eq!(items_game.items().len(), 3);
+        assert_eq!(hat.prefabs, vec!["valve"]);
+        assert_eq!(hat.item_slot, Some(ItemSlot::Misc));
+        assert_eq!(hat.item_quality, Some(Quality::Unique));
+        assert_eq!(hat.craft_class, Some(CraftClass::Hat));
+        assert_eq!(hat.holiday_restriction, Some(HolidayRestriction::HalloweenOrFullmoon));
+        assert_eq!(hat.used_by_classes, vec![Class::Demoman, Class::Heavy]);
+        // The item's own capabilities override the prefab's.
+        assert_eq!(hat.capabilities, vec![Capability::Paintable]);
+        assert_eq!(hat.equip_regions, vec!["hat"]);
+        assert_eq!(hat.attributes[0].attribute_class.as_deref(), Some("set_item_tint_rgb"));
+        assert_eq!(hat.attributes[0].value, "1315860");
+        assert_eq!(items_game.item_by_name("Example Hat").unwrap().defindex, 30000);
+    }
+    
+    #[test]
+    fn loads_attributes() {
+        let items_game = ItemsGame::parse(ITEMS_GAME).unwrap();
+        let attribute = items_game.attribute_by_name("set item tint RGB").unwrap();
+        
+        assert_eq!(attribute.defindex, 142);
+        assert_eq!(attribute.description_format, Some(DescriptionFormat::ValueIsAdditive));
+        assert_eq!(attribute.effect_type, Some(EffectType::Positive));
+        assert!(attribute.hidden);
+        assert!(!attribute.stored_as_integer);
+    }
+    
+    #[test]
+    fn loads_paint_kits() {
+        let items_game = ItemsGame::parse(ITEMS_GAME).unwrap();
+        let war_paint = items_game.item(16000).unwrap();
+        
+        assert_eq!(war_paint.craft_class, None);
+        assert_eq!(war_paint.paint_kit_id(), Some(200));
+        assert_eq!(items_game.paint_kits().len(), 1);
+        assert_eq!(items_game.paint_kit(200).unwrap().item_defindex, 16000);
+        assert_eq!(items_game.paint_kit(200).unwrap().grade, Some(Grade::Commando));
+    }
+    
+    #[test]
+    fn loads_collections() {
+        let items_game = ItemsGame::parse(ITEMS_GAME).unwrap();
+        let collection = items_game.collection("example_collection").unwrap();
+        
+        assert_eq!(collection.items(Grade::Commando), &[16000]);
+        assert_eq!(collection.items(Grade::Assassin), &[30000]);
+        assert_eq!(items_game.item_collection(30000), Some(collection));
+        assert_eq!(PaintKit::from(items_game.paint_kit(200).unwrap()).collection.as_deref(), Some("example_collection"));
+    }
+    
+    #[test]
+    fn loads_unknown_effect_type_as_none() {
+        let input = r#""attributes" { "1" { "name" "a" "effect_type" "sparkly" } "2" { "name" "b" } }"#;
+        let items_game = ItemsGame::parse(input).unwrap();
+        
+        assert_eq!(items_game.attribute_by_name("a").unwrap().effect_type, None);
+        assert_eq!(items_game.attribute_by_name("b").unwrap().effect_type, Some(EffectType::Neutral));
+    }
+}
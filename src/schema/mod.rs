@@ -0,0 +1,731 @@
+//! Loads local dumps of the item schema from the Steam Web API into typed structures.
+//! 
+//! Requires the `schema` feature. Definitions which are only in `items_game.txt`, such as prefabs
+//! and paint kits, can be loaded using [`ItemsGame`].
+//! 
+//! [`Schema`] accepts the responses from `GetSchemaOverview` and
+//! `GetSchemaItems`, either with or without the `result` envelope, or a single object containing
+//! the fields of both. No network requests are made; fetching the schema is left to the caller.
+//! 
+//! Values which are not known to this crate, such as a new capability or an empty `craft_class`,
+//! are skipped rather than failing the entire load.
+//! 
+//! # Examples
+//! ```
+//! use tf2_enum::schema::Schema;
+//! use tf2_enum::{Capability, Class, ItemSlot, Quality};
+//! 
+//! let json = r#"{
+//!     "result": {
+//!         "qualities": { "Normal": 0, "rarity1": 1 },
+//!         "qualityNames": { "Normal": "Normal", "rarity1": "Genuine" },
+//!         "items": [
+//!             {
+//!                 "defindex": 45,
+//!                 "name": "TF_WEAPON_SCATTERGUN_DOUBLE",
+//!                 "item_name": "Force-A-Nature",
+//!                 "item_slot": "primary",
+//!                 "item_quality": 6,
+//!                 "capabilities": { "nameable": true, "paintable": false },
+//!                 "used_by_classes": ["Scout"]
+//!             }
+//!         ]
+//!     }
+//! }"#;
+//! let schema = Schema::from_json_str(json).unwrap();
+//! let item = schema.item(45).unwrap();
+//! 
+//! assert_eq!(item.item_name, "Force-A-Nature");
+//! assert_eq!(item.item_slot, Some(ItemSlot::Primary));
+//! assert_eq!(item.capabilities, vec![Capability::Nameable]);
+//! assert_eq!(item.used_by_classes, vec![Class::Scout]);
+//! assert_eq!(schema.quality_by_name("Genuine").unwrap().quality(), Some(Quality::Genuine));
+//! ```
+
+mod items_game;
+
+pub mod codegen;
+pub mod drift;
+
+pub use items_game::{ItemsGame, ItemsGameItem, ItemsGameItemAttribute, PaintKitDef};
+
+use crate::{
+    Capability,
+    Class,
+    CraftClass,
+    CraftMaterialType,
+    DescriptionFormat,
+    DropType,
+    EffectType,
+    HolidayRestriction,
+    ItemLevel,
+    ItemSlot,
+    KillEaterScoreType,
+    Origin,
+    ParticleEffectRegistry,
+    Quality,
+};
+use crate::error::SchemaError;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// An item definition from the schema.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SchemaItem {
+    /// The item's definition index.
+    pub defindex: u32,
+    /// The internal name of the item.
+    pub name: String,
+    /// The item's class, e.g. "tf_weapon_scattergun".
+    #[serde(default)]
+    pub item_class: String,
+    /// The localized type name, e.g. "Scattergun".
+    #[serde(default)]
+    pub item_type_name: Option<String>,
+    /// The localized name of the item.
+    #[serde(default)]
+    pub item_name: String,
+    /// The localized description of the item.
+    #[serde(default)]
+    pub item_description: Option<String>,
+    /// Whether the item's name should be prefixed with "The".
+    #[serde(default)]
+    pub proper_name: bool,
+    /// The item slot the item is equipped in.
+    #[serde(default, deserialize_with = "from_str_lenient")]
+    pub item_slot: Option<ItemSlot>,
+    /// The default quality of the item.
+    #[serde(default)]
+    pub item_quality: u32,
+    /// The minimum item level.
+    #[serde(default)]
+    pub min_ilevel: u32,
+    /// The maximum item level.
+    #[serde(default)]
+    pub max_ilevel: u32,
+    /// The URL of the item's image.
+    #[serde(default)]
+    pub image_url: Option<String>,
+    /// The URL of the item's large image.
+    #[serde(default)]
+    pub image_url_large: Option<String>,
+    /// The drop type of the item.
+    #[serde(default, deserialize_with = "from_str_lenient")]
+    pub drop_type: Option<DropType>,
+    /// The craft class of the item.
+    #[serde(default, deserialize_with = "from_str_lenient")]
+    pub craft_class: Option<CraftClass>,
+    /// The craft material type of the item.
+    #[serde(default, deserialize_with = "from_str_lenient")]
+    pub craft_material_type: Option<CraftMaterialType>,
+    /// The capabilities of the item which are enabled.
+    #[serde(default, deserialize_with = "capabilities")]
+    pub capabilities: Vec<Capability>,
+    /// The classes which can use the item. Empty if the item is usable by all classes.
+    #[serde(default, deserialize_with = "vec_from_str_lenient")]
+    pub used_by_classes: Vec<Class>,
+    /// The holiday the item is restricted to.
+    #[serde(default, deserialize_with = "from_str_lenient")]
+    pub holiday_restriction: Option<HolidayRestriction>,
+    /// The static attributes of the item.
+    #[serde(default)]
+    pub attributes: Vec<SchemaItemAttribute>,
+}
+
+impl SchemaItem {
+    /// Gets the default quality of the item.
+    pub fn quality(&self) -> Option<Quality> {
+        Quality::try_from(self.item_quality).ok()
+    }
+    
+    /// Checks if the item has a capability.
+    pub fn has_capability(&self, capability: Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+    
+    /// Checks if the item can be used by a class.
+    pub fn is_used_by(&self, class: Class) -> bool {
+        self.used_by_classes.is_empty() || self.used_by_classes.contains(&class)
+    }
+}
+
+/// A static attribute on an item definition.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SchemaItemAttribute {
+    /// The name of the attribute.
+    pub name: String,
+    /// The attribute class of the attribute.
+    #[serde(default)]
+    pub class: Option<String>,
+    /// The value of the attribute.
+    #[serde(default)]
+    pub value: f64,
+}
+
+/// An attribute definition from the schema. This is the owned counterpart of
+/// [`AttributeDef`][`crate::AttributeDef`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SchemaAttribute {
+    /// The unique identifier for the attribute.
+    pub defindex: u32,
+    /// The name of the attribute.
+    pub name: String,
+    /// The attribute class of the attribute.
+    #[serde(default)]
+    pub attribute_class: Option<String>,
+    /// The description string of the attribute.
+    #[serde(default)]
+    pub description_string: Option<String>,
+    /// The description format of the attribute.
+    #[serde(default, deserialize_with = "from_str_lenient")]
+    pub description_format: Option<DescriptionFormat>,
+    /// The effect type of the description. `None` if the effect type is not known to this crate.
+    #[serde(default, deserialize_with = "from_str_lenient")]
+    pub effect_type: Option<EffectType>,
+    /// Indicates whether the description is hidden from display.
+    #[serde(default)]
+    pub hidden: bool,
+    /// Indicates whether the attribute's value is stored as an integer.
+    #[serde(default)]
+    pub stored_as_integer: bool,
+}
+
+impl SchemaAttribute {
+    /// Returns the description of the attribute with the supplied value.
+    pub fn description<F>(&self, value: Option<F>) -> Option<String>
+    where
+        F: std::fmt::Display,
+    {
+        let description_string = self.description_string.as_ref()?;
+        
+        if let Some(value) = value {
+            return description_string
+                .replace("%s1", &value.to_string())
+                .into();
+        }
+        
+        Some(description_string.to_string())
+    }
+}
+
+/// A quality from the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SchemaQuality {
+    /// The ID of the quality.
+    pub id: u32,
+    /// The internal name of the quality, e.g. "rarity1".
+    pub name: String,
+    /// The localized name of the quality, e.g. "Genuine".
+    pub display_name: String,
+}
+
+impl SchemaQuality {
+    /// Gets the [`Quality`] for this quality.
+    pub fn quality(&self) -> Option<Quality> {
+        Quality::try_from(self.id).ok()
+    }
+}
+
+/// An origin from the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SchemaOrigin {
+    /// The ID of the origin.
+    pub origin: u32,
+    /// The name of the origin.
+    pub name: String,
+}
+
+impl SchemaOrigin {
+    /// Gets the [`Origin`] for this origin.
+    pub fn origin(&self) -> Option<Origin> {
+        Origin::try_from(self.origin).ok()
+    }
+}
+
+/// A set of item levels from the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SchemaItemLevel {
+    /// The name of the item level set, e.g. "KillEaterRank".
+    pub name: String,
+    /// The levels.
+    #[serde(default)]
+    pub levels: Vec<SchemaLevel>,
+}
+
+impl SchemaItemLevel {
+    /// Gets the [`ItemLevel`] for this item level set.
+    pub fn item_level(&self) -> Option<ItemLevel> {
+        ItemLevel::from_str(&self.name).ok()
+    }
+}
+
+/// A kill eater score type from the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SchemaKillEaterScoreType {
+    /// The ID of the score type.
+    #[serde(rename = "type")]
+    pub id: u32,
+    /// The name of the score type, e.g. "Kills".
+    pub type_name: String,
+    /// The name of the item level set used for ranks, e.g. "KillEaterRank".
+    pub level_data: String,
+}
+
+impl SchemaKillEaterScoreType {
+    /// Gets the [`KillEaterScoreType`] for this score type.
+    pub fn kill_eater_score_type(&self) -> Option<KillEaterScoreType> {
+        KillEaterScoreType::try_from(self.id).ok()
+    }
+}
+
+/// A level within a set of item levels.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SchemaLevel {
+    /// The level.
+    pub level: u32,
+    /// The required score to reach this level.
+    pub required_score: i32,
+    /// The name of the level.
+    pub name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawSchema {
+    #[serde(default)]
+    qualities: HashMap<String, u32>,
+    #[serde(default, rename = "qualityNames")]
+    quality_names: HashMap<String, String>,
+    #[serde(default, rename = "originNames")]
+    origin_names: Vec<SchemaOrigin>,
+    #[serde(default)]
+    attributes: Vec<SchemaAttribute>,
+    #[serde(default)]
+    item_levels: Vec<SchemaItemLevel>,
+    #[serde(default)]
+    kill_eater_score_types: Vec<SchemaKillEaterScoreType>,
+    #[serde(default)]
+    attribute_controlled_attached_particles: ParticleEffectRegistry,
+    #[serde(default)]
+    items: Vec<SchemaItem>,
+}
+
+/// An item schema loaded from a local dump.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    items: Vec<SchemaItem>,
+    attributes: Vec<SchemaAttribute>,
+    qualities: Vec<SchemaQuality>,
+    origins: Vec<SchemaOrigin>,
+    item_levels: Vec<SchemaItemLevel>,
+    kill_eater_score_types: Vec<SchemaKillEaterScoreType>,
+    particle_effects: ParticleEffectRegistry,
+    items_by_defindex: HashMap<u32, usize>,
+    items_by_name: HashMap<String, usize>,
+    attributes_by_defindex: HashMap<u32, usize>,
+    attributes_by_name: HashMap<String, usize>,
+}
+
+impl Schema {
+    /// Loads a schema from a JSON string.
+    pub fn from_json_str(json: &str) -> Result<Self, SchemaError> {
+        Self::from_value(serde_json::from_str(json)?)
+    }
+    
+    /// Loads a schema from JSON bytes.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, SchemaError> {
+        Self::from_value(serde_json::from_slice(bytes)?)
+    }
+    
+    /// Loads a schema from a reader containing JSON.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, SchemaError> {
+        Self::from_value(serde_json::from_reader(reader)?)
+    }
+    
+    /// Loads a schema from a JSON file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, SchemaError> {
+        let file = std::fs::File::open(path)?;
+        
+        Self::from_reader(std::io::BufReader::new(file))
+    }
+    
+    fn from_value(mut value: serde_json::Value) -> Result<Self, SchemaError> {
+        if let Some(result) = value.get_mut("result") {
+            value = result.take();
+        }
+        
+        let raw: RawSchema = serde_json::from_value(value)?;
+        let mut qualities = raw.qualities
+            .into_iter()
+            .map(|(name, id)| SchemaQuality {
+                id,
+                display_name: raw.quality_names
+                    .get(&name)
+                    .cloned()
+                    .unwrap_or_else(|| name.clone()),
+                name,
+            })
+            .collect::<Vec<_>>();
+        
+        qualities.sort_by_key(|quality| quality.id);
+        
+        let mut schema = Self {
+            items: raw.items,
+            attributes: raw.attributes,
+            qualities,
+            origins: raw.origin_names,
+            item_levels: raw.item_levels,
+            kill_eater_score_types: raw.kill_eater_score_types,
+            particle_effects: raw.attribute_controlled_attached_particles,
+            ..Default::default()
+        };
+        
+        schema.index();
+        Ok(schema)
+    }
+    
+    /// Merges another schema into this one. Definitions in `other` replace definitions in this
+    /// schema with the same identifier. This is useful for combining the overview with pages of
+    /// items which were dumped separately.
+    pub fn merge(&mut self, other: Schema) {
+        fn merge_by<T, K: Eq + Hash>(into: &mut Vec<T>, from: Vec<T>, key: impl Fn(&T) -> K) {
+            let mut indexes = HashMap::with_capacity(into.len() + from.len());
+            
+            for (i, value) in into.iter().enumerate() {
+                indexes.entry(key(value)).or_insert(i);
+            }
+            
+            for value in from {
+                match indexes.entry(key(&value)) {
+                    Entry::Occupied(entry) => into[*entry.get()] = value,
+                    Entry::Vacant(entry) => {
+                        entry.insert(into.len());
+                        into.push(value);
+                    },
+                }
+            }
+        }
+        
+        merge_by(&mut self.items, other.items, |item| item.defindex);
+        merge_by(&mut self.attributes, other.attributes, |attribute| attribute.defindex);
+        merge_by(&mut self.qualities, other.qualities, |quality| quality.id);
+        merge_by(&mut self.origins, other.origins, |origin| origin.origin);
+        merge_by(&mut self.item_levels, other.item_levels, |item_level| item_level.name.clone());
+        merge_by(&mut self.kill_eater_score_types, other.kill_eater_score_types, |score_type| score_type.id);
+        self.particle_effects.extend(other.particle_effects.iter().cloned());
+        self.index();
+    }
+    
+    fn index(&mut self) {
+        self.items_by_defindex.clear();
+        self.items_by_name.clear();
+        self.attributes_by_defindex.clear();
+        self.attributes_by_name.clear();
+        
+        for (i, item) in self.items.iter().enumerate() {
+            self.items_by_defindex.insert(item.defindex, i);
+            self.items_by_name.entry(item.name.clone()).or_insert(i);
+        }
+        
+        for (i, attribute) in self.attributes.iter().enumerate() {
+            self.attributes_by_defindex.insert(attribute.defindex, i);
+            self.attributes_by_name.entry(attribute.name.clone()).or_insert(i);
+        }
+    }
+    
+    /// Gets all item definitions.
+    pub fn items(&self) -> &[SchemaItem] {
+        &self.items
+    }
+    
+    /// Gets an item definition by its defindex.
+    pub fn item(&self, defindex: u32) -> Option<&SchemaItem> {
+        self.items_by_defindex.get(&defindex).map(|i| &self.items[*i])
+    }
+    
+    /// Gets an item definition by its internal name, e.g. "TF_WEAPON_SCATTERGUN_DOUBLE". If
+    /// multiple items share a name, the first is returned.
+    pub fn item_by_name(&self, name: &str) -> Option<&SchemaItem> {
+        self.items_by_name.get(name).map(|i| &self.items[*i])
+    }
+    
+    /// Gets all attribute definitions.
+    pub fn attributes(&self) -> &[SchemaAttribute] {
+        &self.attributes
+    }
+    
+    /// Gets an attribute definition by its defindex.
+    pub fn attribute(&self, defindex: u32) -> Option<&SchemaAttribute> {
+        self.attributes_by_defindex.get(&defindex).map(|i| &self.attributes[*i])
+    }
+    
+    /// Gets an attribute definition by its name.
+    pub fn attribute_by_name(&self, name: &str) -> Option<&SchemaAttribute> {
+        self.attributes_by_name.get(name).map(|i| &self.attributes[*i])
+    }
+    
+    /// Gets all qualities, ordered by ID.
+    pub fn qualities(&self) -> &[SchemaQuality] {
+        &self.qualities
+    }
+    
+    /// Gets a quality by its ID.
+    pub fn quality(&self, id: u32) -> Option<&SchemaQuality> {
+        self.qualities.iter().find(|quality| quality.id == id)
+    }
+    
+    /// Gets a quality by either its internal name or localized name.
+    pub fn quality_by_name(&self, name: &str) -> Option<&SchemaQuality> {
+        self.qualities
+            .iter()
+            .find(|quality| quality.name == name || quality.display_name == name)
+    }
+    
+    /// Gets all origins.
+    pub fn origins(&self) -> &[SchemaOrigin] {
+        &self.origins
+    }
+    
+    /// Gets an origin by its ID.
+    pub fn origin(&self, origin: u32) -> Option<&SchemaOrigin> {
+        self.origins.iter().find(|value| value.origin == origin)
+    }
+    
+    /// Gets an origin by its name.
+    pub fn origin_by_name(&self, name: &str) -> Option<&SchemaOrigin> {
+        self.origins.iter().find(|origin| origin.name == name)
+    }
+    
+    /// Gets all item level sets.
+    pub fn item_levels(&self) -> &[SchemaItemLevel] {
+        &self.item_levels
+    }
+    
+    /// Gets an item level set by its name.
+    pub fn item_level(&self, name: &str) -> Option<&SchemaItemLevel> {
+        self.item_levels.iter().find(|item_level| item_level.name == name)
+    }
+    
+    /// Gets all kill eater score types.
+    pub fn kill_eater_score_types(&self) -> &[SchemaKillEaterScoreType] {
+        &self.kill_eater_score_types
+    }
+    
+    /// Gets a kill eater score type by its ID.
+    pub fn kill_eater_score_type(&self, id: u32) -> Option<&SchemaKillEaterScoreType> {
+        self.kill_eater_score_types.iter().find(|score_type| score_type.id == id)
+    }
+    
+    /// Gets the unusual particle effects from the `attribute_controlled_attached_particles`
+    /// section.
+    pub fn particle_effects(&self) -> &ParticleEffectRegistry {
+        &self.particle_effects
+    }
+}
+
+/// Deserializes a string into a value, skipping values which fail to parse.
+fn from_str_lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    
+    Ok(value.and_then(|value| value.parse().ok()))
+}
+
+/// Deserializes a sequence of strings into values, skipping values which fail to parse.
+fn vec_from_str_lenient<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    let values = Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default();
+    
+    Ok(values.iter().filter_map(|value| value.parse().ok()).collect())
+}
+
+/// Deserializes a map of capabilities into the capabilities which are enabled.
+fn capabilities<'de, D>(deserializer: D) -> Result<Vec<Capability>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let map = Option::<HashMap<String, bool>>::deserialize(deserializer)?.unwrap_or_default();
+    let mut capabilities = map
+        .into_iter()
+        .filter(|(_, enabled)| *enabled)
+        .filter_map(|(name, _)| name.parse().ok())
+        .collect::<Vec<Capability>>();
+    
+    capabilities.sort();
+    Ok(capabilities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const OVERVIEW: &str = r#"{
+        "result": {
+            "status": 1,
+            "qualities": { "Normal": 0, "rarity1": 1, "Unique": 6 },
+            "qualityNames": { "Normal": "Normal", "rarity1": "Genuine", "Unique": "Unique" },
+            "originNames": [
+                { "origin": 0, "name": "Timed Drop" },
+                { "origin": 1, "name": "Achievement" }
+            ],
+            "attributes": [
+                {
+                    "name": "set_item_tint_rgb",
+                    "defindex": 142,
+                    "attribute_class": "set_item_tint_rgb",
+                    "description_string": "Item tint color code: %s1",
+                    "description_format": "value_is_additive",
+                    "effect_type": "positive",
+                    "hidden": true,
+                    "stored_as_integer": false
+                },
+                {
+                    "name": "new attribute",
+                    "defindex": 9999,
+                    "description_format": "value_is_something_new",
+                    "effect_type": "something_new"
+                }
+            ],
+            "attribute_controlled_attached_particles": [
+                { "system": "superrare_burning1", "id": 13, "attach_to_rootbone": false, "name": "Burning Flames" },
+                { "system": "utaunt_firework_teamcolor_red", "id": 3001, "attach_to_rootbone": true, "name": "Showstopper" }
+            ],
+            "item_levels": [
+                {
+                    "name": "KillEaterRank",
+                    "levels": [
+                        { "level": 0, "required_score": 10, "name": "Strange" },
+                        { "level": 1, "required_score": 25, "name": "Unremarkable" }
+                    ]
+                }
+            ],
+            "kill_eater_score_types": [
+                { "type": 1, "type_name": "Übers", "level_data": "KillEaterRank" }
+            ]
+        }
+    }"#;
+    const ITEMS: &str = r#"{
+        "result": {
+            "status": 1,
+            "items": [
+                {
+                    "name": "Paint Can 1",
+                    "defindex": 5052,
+                    "item_class": "tool",
+                    "item_type_name": "Tool",
+                    "item_name": "A Distinctive Lack of Hue",
+                    "proper_name": false,
+                    "item_quality": 6,
+                    "min_ilevel": 5,
+                    "max_ilevel": 5,
+                    "image_url": "",
+                    "craft_class": "tool",
+                    "craft_material_type": "tool",
+                    "capabilities": { "can_gift_wrap": true, "some_new_capability": true },
+                    "attributes": [
+                        { "name": "set item tint RGB", "class": "set_item_tint_rgb", "value": 1315860 }
+                    ]
+                },
+                {
+                    "name": "TF_WEAPON_SCATTERGUN_DOUBLE",
+                    "defindex": 45,
+                    "item_class": "tf_weapon_scattergun",
+                    "item_name": "Force-A-Nature",
+                    "proper_name": true,
+                    "item_slot": "primary",
+                    "item_quality": 6,
+                    "craft_class": "weapon",
+                    "drop_type": "drop",
+                    "holiday_restriction": null,
+                    "used_by_classes": ["Scout"]
+                },
+                {
+                    "name": "Ghastly Gibus",
+                    "defindex": 584,
+                    "item_name": "Ghastly Gibus",
+                    "item_slot": "misc",
+                    "item_quality": 6,
+                    "craft_class": "",
+                    "holiday_restriction": "halloween_or_fullmoon"
+                }
+            ]
+        }
+    }"#;
+    
+    #[test]
+    fn loads_overview() {
+        let schema = Schema::from_json_str(OVERVIEW).unwrap();
+        let attribute = schema.attribute(142).unwrap();
+        
+        assert_eq!(attribute.description_format, Some(DescriptionFormat::ValueIsAdditive));
+        assert_eq!(attribute.effect_type, Some(EffectType::Positive));
+        assert_eq!(attribute.description(Some(1)), Some("Item tint color code: 1".into()));
+        assert_eq!(schema.attribute_by_name("set_item_tint_rgb").unwrap().defindex, 142);
+        assert_eq!(schema.attribute(9999).unwrap().description_format, None);
+        assert_eq!(schema.attribute(9999).unwrap().effect_type, None);
+        assert_eq!(schema.qualities().len(), 3);
+        assert_eq!(schema.quality(1).unwrap().display_name, "Genuine");
+        assert_eq!(schema.quality_by_name("rarity1").unwrap().quality(), Some(Quality::Genuine));
+        assert_eq!(schema.origin_by_name("Achievement").unwrap().origin(), Some(Origin::Achievement));
+        assert_eq!(schema.item_level("KillEaterRank").unwrap().item_level(), Some(ItemLevel::KillEaterRank));
+        assert_eq!(schema.particle_effects().get(13).unwrap().system.as_deref(), Some("superrare_burning1"));
+        assert!(schema.particle_effects().get_by_name("Showstopper").unwrap().attach_to_rootbone);
+        assert_eq!(schema.kill_eater_score_type(1).unwrap().kill_eater_score_type(), Some(KillEaterScoreType::Ubers));
+    }
+    
+    #[test]
+    fn loads_items() {
+        let schema = Schema::from_json_str(ITEMS).unwrap();
+        let paint = schema.item_by_name("Paint Can 1").unwrap();
+        
+        assert_eq!(paint.craft_class, Some(CraftClass::Tool));
+        assert_eq!(paint.capabilities, vec![Capability::CanGiftWrap]);
+        assert_eq!(paint.attributes[0].value, 1315860.0);
+        
+        let force_a_nature = schema.item(45).unwrap();
+        
+        assert!(force_a_nature.proper_name);
+        assert_eq!(force_a_nature.drop_type, Some(DropType::Drop));
+        assert!(force_a_nature.is_used_by(Class::Scout));
+        assert!(!force_a_nature.is_used_by(Class::Spy));
+        
+        let gibus = schema.item(584).unwrap();
+        
+        assert_eq!(gibus.craft_class, None);
+        assert_eq!(gibus.holiday_restriction, Some(HolidayRestriction::HalloweenOrFullmoon));
+        assert!(gibus.is_used_by(Class::Spy));
+    }
+    
+    #[test]
+    fn merges_schemas() {
+        let mut schema = Schema::from_json_str(OVERVIEW).unwrap();
+        
+        schema.merge(Schema::from_json_str(ITEMS).unwrap());
+        
+        assert_eq!(schema.items().len(), 3);
+        assert_eq!(schema.attributes().len(), 2);
+        assert!(schema.item(45).is_some());
+        assert!(schema.attribute(142).is_some());
+        
+        // Definitions with the same identifier are replaced rather than duplicated.
+        schema.merge(Schema::from_json_str(ITEMS).unwrap());
+        
+        assert_eq!(schema.items().len(), 3);
+        assert_eq!(schema.item(584).unwrap().item_name, "Ghastly Gibus");
+    }
+    
+    #[test]
+    fn rejects_invalid_json() {
+        assert!(matches!(Schema::from_json_str("{"), Err(SchemaError::Json(_))));
+    }
+}
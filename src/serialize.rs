@@ -1,9 +1,23 @@
 //! Serialization utilities.
 
-use crate::AttributeSet;
+use crate::{AttributeSet, ItemAttribute};
+use crate::error::AttributeValueError;
+use std::collections::HashSet;
+use serde::de::{self, SeqAccess};
 use serde::ser::SerializeSeq;
 use serde::Serializer;
 
+/// Serializes each variant of an enum to describe the allowed values in schemas.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+pub fn enum_values<T>() -> Vec<serde_json::Value>
+where
+    T: strum::IntoEnumIterator + serde::Serialize,
+{
+    T::iter()
+        .map(|value| serde_json::to_value(value).expect("enums are always serializable"))
+        .collect()
+}
+
 /// Serializes a float into an integer when the float is a whole number.
 pub fn option_float_as_integers_when_whole<S: Serializer>(
     value: &Option<f32>,
@@ -16,6 +30,23 @@ pub fn option_float_as_integers_when_whole<S: Serializer>(
     }
 }
 
+/// Collects decoded items into an attribute set. Fails if an item is repeated or there are too
+/// many items.
+#[cfg(feature = "sqlx-postgres-0_8")]
+pub fn try_collect_attribute_set<T, I>(items: I) -> Result<T, crate::error::InsertError>
+where
+    T: AttributeSet,
+    I: IntoIterator<Item = T::Item>,
+{
+    let mut set = T::NONE;
+    
+    for item in items {
+        set.try_insert(item)?;
+    }
+    
+    Ok(set)
+}
+
 pub fn serialize_attribute_set<S, T>(set: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -28,4 +59,51 @@ where
     }
     
     seq.end()
-}
\ No newline at end of file
+}
+
+/// Deserializes a sequence of attributes into an attribute set. Attributes which `convert` does
+/// not recognize are ignored, as are repeated defindexes. Attributes which fail to convert are
+/// passed to `skip`, which decides whether deserialization fails.
+pub fn deserialize_attribute_set<'de, A, T, F>(
+    mut seq: A,
+    convert: fn(&ItemAttribute) -> Result<Option<T::Item>, AttributeValueError>,
+    mut skip: F,
+) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: AttributeSet,
+    F: FnMut(ItemAttribute, AttributeValueError) -> Result<(), A::Error>,
+{
+    let mut set = T::NONE;
+    let mut defindexes = HashSet::new();
+    
+    while let Some(attribute) = seq.next_element::<ItemAttribute>()? {
+        let item = match convert(&attribute) {
+            Ok(None) => continue,
+            // Skip if defindex is already in the set
+            _ if defindexes.contains(&attribute.defindex) => continue,
+            Ok(Some(item)) => item,
+            Err(error) => {
+                skip(attribute, error)?;
+                continue;
+            },
+        };
+        
+        // Only claimed once converted, so a skipped attribute doesn't hide a later valid one.
+        defindexes.insert(attribute.defindex);
+        set.insert(item);
+    }
+    
+    Ok(set)
+}
+
+/// Fails on the first attribute which cannot be converted.
+pub fn strict_attribute<E: de::Error>(
+    _attribute: ItemAttribute,
+    error: AttributeValueError,
+) -> Result<(), E> {
+    match error {
+        AttributeValueError::MissingFloatValue => Err(E::missing_field("float_value")),
+        AttributeValueError::UnknownValue => Err(E::custom("cannot convert from float_value")),
+    }
+}
@@ -1,14 +1,17 @@
 use crate::{
     Attribute,
     AttributeDef,
+    Colored,
     DescriptionFormat,
     EffectType,
     ItemAttribute,
+    TeamColored,
     TryFromIntAttributeValue,
 };
+use crate::color;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Sheen.
 #[derive(
@@ -25,10 +28,14 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[strum(serialize_all = "title_case")]
 #[allow(missing_docs)]
@@ -42,6 +49,68 @@ pub enum Sheen {
     HotRod = 7,
 }
 
+impl Sheen {
+    /// Gets the glow colors for both teams. The RED team appears first. For sheens which aren't
+    /// team-colored, the color will be the same.
+    pub fn colors(&self) -> (u32, u32) {
+        match self {
+            Self::TeamShine => (0xC8140F, 0x2864FF),
+            Self::DeadlyDaffodil => (0xF2AC0A, 0xF2AC0A),
+            Self::Manndarin => (0xFF4B05, 0xFF4B05),
+            Self::MeanGreen => (0x64FF0A, 0x64FF0A),
+            Self::AgonizingEmerald => (0x28FF46, 0x28FF46),
+            Self::VillainousViolet => (0x6914FF, 0x6914FF),
+            Self::HotRod => (0xFF28D2, 0xFF28D2),
+        }
+    }
+    
+    /// Determines if this sheen is team-colored.
+    pub fn is_team_colored(&self) -> bool {
+        matches!(self, Self::TeamShine)
+    }
+}
+
+impl Colored for Sheen {
+    /// Gets the glow color of the [`Sheen`]. For team-colored sheens, this is the color for team
+    /// RED.
+    fn color(&self) -> u32 {
+        self.colors().0
+    }
+    
+    /// Converts a hexadecimal color into a [`Sheen`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Sheen, Colored};
+    /// 
+    /// assert_eq!(Sheen::from_color(0xF2AC0A).unwrap(), Sheen::DeadlyDaffodil);
+    /// ```
+    fn from_color(color: u32) -> Option<Self> {
+        match color {
+            0xC8140F => Some(Self::TeamShine),
+            0xF2AC0A => Some(Self::DeadlyDaffodil),
+            0xFF4B05 => Some(Self::Manndarin),
+            0x64FF0A => Some(Self::MeanGreen),
+            0x28FF46 => Some(Self::AgonizingEmerald),
+            0x6914FF => Some(Self::VillainousViolet),
+            0xFF28D2 => Some(Self::HotRod),
+            _ => None,
+        }
+    }
+    
+    /// Gets the colors for both teams.
+    fn match_colors(&self) -> Vec<u32> {
+        color::team_match_colors(self.colors())
+    }
+}
+
+impl TeamColored for Sheen {
+    /// Gets the glow colors for both teams. See [`Sheen::colors`].
+    fn colors(&self) -> (u32, u32) {
+        Sheen::colors(self)
+    }
+}
+
 impl Attribute for Sheen {
     const DEFINDEX: u32 = 2014;
     const USES_FLOAT_VALUE: bool = true;
@@ -102,4 +171,11 @@ mod tests {
         
         assert_eq!(attribute_float_value(Sheen::DeadlyDaffodil), Some(2.0));
     }
+    
+    #[test]
+    fn colors() {
+        assert_eq!(Sheen::TeamShine.color_string(), "#C8140F");
+        assert_eq!(Sheen::from_color_nearest(0x2864FF).unwrap().value, Sheen::TeamShine);
+        assert_eq!(Sheen::from_color_nearest(0x6A15FE).unwrap().value, Sheen::VillainousViolet);
+    }
 }
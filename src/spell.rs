@@ -1,12 +1,15 @@
-use crate::error::TryFromSpellError;
+use crate::error::{SpellApplicabilityError, TryFromSpellError};
 use crate::{
     Attribute,
     Attributes,
     AttributeDef,
     AttributeValue,
+    Capability,
     DescriptionFormat,
     EffectType,
     ItemAttribute,
+    ItemSlot,
+    MaybeColored,
     TryFromIntAttributeValue,
 };
 use crate::econ_attributes::{
@@ -21,7 +24,7 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::de::{self, Deserializer, Visitor};
 use serde::{Deserialize, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 /// Spell.
 /// 
@@ -37,10 +40,14 @@ use strum::{Display, EnumCount, EnumIter, EnumString};
     Display,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     Clone,
     Copy,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Text))]
 #[strum(serialize_all = "title_case")]
 #[allow(missing_docs)]
 pub enum Spell {
@@ -146,6 +153,112 @@ impl Spell {
             Self::RottenOrangeFootprints,
         )
     }
+    
+    /// Gets the color of this spell. This will only return a value for footprints spells which
+    /// are identified by their color. See [`FootprintsSpell::color`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::Spell;
+    /// 
+    /// assert_eq!(Spell::GangreenFootprints.color(), Some(0x808000));
+    /// assert_eq!(Spell::TeamSpiritFootprints.color(), None);
+    /// assert_eq!(Spell::Exorcism.color(), None);
+    /// ```
+    pub fn color(&self) -> Option<u32> {
+        FootprintsSpell::try_from(self)
+            .ok()
+            .and_then(|spell| spell.color())
+    }
+    
+    /// Checks if this spell is a weapon spell.
+    pub fn is_weapon_spell(&self) -> bool {
+        matches!(
+            self,
+            Self::PumpkinBombs |
+            Self::HalloweenFire |
+            Self::Exorcism,
+        )
+    }
+    
+    /// Gets the item slots this spell can be applied to.
+    pub fn applicable_item_slots(&self) -> &'static [ItemSlot] {
+        if self.is_weapon_spell() {
+            ItemSlot::WEAPONS
+        } else {
+            ItemSlot::COSMETICS
+        }
+    }
+    
+    /// Checks whether this spell can be applied to an item in the given slot, returning the
+    /// reason if it can't. This does not check whether the item is paintable, use
+    /// [`Spell::check_applicable_to_item`] if the item's capabilities are known.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Spell, ItemSlot};
+    /// use tf2_enum::error::SpellApplicabilityError;
+    /// 
+    /// assert!(Spell::Exorcism.check_applicable_to(ItemSlot::Melee).is_ok());
+    /// assert_eq!(
+    ///     Spell::HeadlessHorseshoes.check_applicable_to(ItemSlot::Primary),
+    ///     Err(SpellApplicabilityError::CosmeticsOnly),
+    /// );
+    /// ```
+    pub fn check_applicable_to(&self, item_slot: ItemSlot) -> Result<(), SpellApplicabilityError> {
+        if self.applicable_item_slots().contains(&item_slot) {
+            return Ok(());
+        }
+        
+        if self.is_weapon_spell() {
+            Err(SpellApplicabilityError::WeaponsOnly)
+        } else {
+            Err(SpellApplicabilityError::CosmeticsOnly)
+        }
+    }
+    
+    /// Checks whether this spell can be applied to an item in the given slot with the given
+    /// capabilities, returning the reason if it can't. Paint spells require the item to be
+    /// [`Capability::Paintable`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Spell, ItemSlot, Capability};
+    /// use tf2_enum::error::SpellApplicabilityError;
+    /// 
+    /// assert!(Spell::DieJob.check_applicable_to_item(ItemSlot::Misc, &[Capability::Paintable]).is_ok());
+    /// assert_eq!(
+    ///     Spell::DieJob.check_applicable_to_item(ItemSlot::Misc, &[]),
+    ///     Err(SpellApplicabilityError::NotPaintable),
+    /// );
+    /// ```
+    pub fn check_applicable_to_item(
+        &self,
+        item_slot: ItemSlot,
+        capabilities: &[Capability],
+    ) -> Result<(), SpellApplicabilityError> {
+        self.check_applicable_to(item_slot)?;
+        
+        if self.is_paint_spell() && !capabilities.contains(&Capability::Paintable) {
+            return Err(SpellApplicabilityError::NotPaintable);
+        }
+        
+        Ok(())
+    }
+    
+    /// Checks whether this spell can be applied to an item in the given slot.
+    pub fn is_applicable_to(&self, item_slot: ItemSlot) -> bool {
+        self.check_applicable_to(item_slot).is_ok()
+    }
+}
+
+impl MaybeColored for Spell {
+    /// Gets the colors of this spell for both teams. See [`Spell::color`].
+    fn colors(&self) -> Option<(u32, u32)> {
+        FootprintsSpell::try_from(self)
+            .ok()
+            .and_then(|spell| spell.colors())
+    }
 }
 
 impl Attributes for Spell {
@@ -390,6 +503,9 @@ impl Serialize for Spell {
 }
 
 /// Paint spell.
+/// 
+/// Paint spells are rendered with material effects rather than a color, and the game data has no
+/// RGB values for them, so they don't implement [`MaybeColored`].
 #[derive(
     Debug,
     Clone,
@@ -404,10 +520,14 @@ impl Serialize for Spell {
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[strum(serialize_all = "title_case")]
 #[allow(missing_docs)]
@@ -419,6 +539,13 @@ pub enum PaintSpell {
     SinisterStaining = 4,
 }
 
+impl PaintSpell {
+    /// Determines if this spell is team-colored.
+    pub fn is_team_colored(&self) -> bool {
+        matches!(self, Self::SpectralSpectrum)
+    }
+}
+
 impl Attribute for PaintSpell {
     const DEFINDEX: u32 = 1004;
     const USES_FLOAT_VALUE: bool = true;
@@ -482,6 +609,8 @@ impl From<PaintSpell> for ItemAttribute {
 }
 
 /// Footprints spell.
+/// 
+/// Colors are available through [`MaybeColored`] for footprints identified by their color.
 #[derive(
     Debug,
     Clone,
@@ -496,10 +625,14 @@ impl From<PaintSpell> for ItemAttribute {
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[strum(serialize_all = "title_case")]
 #[allow(missing_docs)]
@@ -513,6 +646,38 @@ pub enum FootprintsSpell {
     RottenOrangeFootprints = 13595446,
 }
 
+impl FootprintsSpell {
+    /// Gets the color of the footprints. Most footprints spells are identified by their color in
+    /// the "halloween_footstep_type" attribute, so this is the same as their `repr` value.
+    /// [`FootprintsSpell::TeamSpiritFootprints`] and [`FootprintsSpell::HeadlessHorseshoes`] are
+    /// identified by an index instead. Their colors aren't stored in the game data, so they have
+    /// no color.
+    pub fn color(&self) -> Option<u32> {
+        match self {
+            Self::TeamSpiritFootprints |
+            Self::HeadlessHorseshoes => None,
+            Self::CorpseGrayFootprints |
+            Self::ViolentVioletFootprints |
+            Self::BruisedPurpleFootprints |
+            Self::GangreenFootprints |
+            Self::RottenOrangeFootprints => Some(*self as u32),
+        }
+    }
+    
+    /// Determines if this spell is team-colored.
+    pub fn is_team_colored(&self) -> bool {
+        matches!(self, Self::TeamSpiritFootprints)
+    }
+}
+
+impl MaybeColored for FootprintsSpell {
+    /// Gets the footprints color for both teams. Footprints identified by their color are the same
+    /// for both teams. See [`FootprintsSpell::color`].
+    fn colors(&self) -> Option<(u32, u32)> {
+        FootprintsSpell::color(self).map(|color| (color, color))
+    }
+}
+
 impl Attribute for FootprintsSpell {
     const DEFINDEX: u32 = 1005;
     const USES_FLOAT_VALUE: bool = true;
@@ -576,7 +741,9 @@ impl From<FootprintsSpell> for ItemAttribute {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Team;
     use std::str::FromStr;
+    use strum::IntoEnumIterator;
     
     #[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
     struct SpellAttribute {
@@ -630,4 +797,40 @@ mod tests {
     fn attribute_slices_are_equal_length() {
         assert_eq!(Spell::DEFINDEX.len(), Spell::ATTRIBUTES.len());
     }
+    
+    #[test]
+    fn footprints_colors_match_attribute_values() {
+        for spell in FootprintsSpell::iter() {
+            if spell.attribute_float_value().unwrap() > 2.0 {
+                assert_eq!(spell.color(), Some(spell as u32));
+                assert_eq!(spell.color_for(Team::Blu), Some(spell as u32));
+                assert_eq!(FootprintsSpell::from_color(spell as u32), Some(spell));
+            } else {
+                assert_eq!(spell.colors(), None);
+            }
+        }
+    }
+    
+    #[test]
+    fn spell_colors_match_footprints_colors() {
+        assert_eq!(Spell::GangreenFootprints.colors(), Some((0x808000, 0x808000)));
+        assert_eq!(Spell::from_color(0x808000), Some(Spell::GangreenFootprints));
+        assert_eq!(Spell::HeadlessHorseshoes.colors(), None);
+        assert_eq!(Spell::DieJob.colors(), None);
+    }
+    
+    #[test]
+    fn checks_applicability() {
+        assert!(Spell::PumpkinBombs.is_applicable_to(ItemSlot::Primary));
+        assert!(!Spell::PumpkinBombs.is_applicable_to(ItemSlot::Misc));
+        assert!(Spell::VoicesFromBelow.is_applicable_to(ItemSlot::Misc));
+        assert_eq!(
+            Spell::HalloweenFire.check_applicable_to(ItemSlot::Misc),
+            Err(SpellApplicabilityError::WeaponsOnly),
+        );
+        assert_eq!(
+            Spell::SpectralSpectrum.check_applicable_to_item(ItemSlot::Melee, &[Capability::Paintable]),
+            Err(SpellApplicabilityError::CosmeticsOnly),
+        );
+    }
 }
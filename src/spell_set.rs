@@ -3,20 +3,23 @@
 use crate::{
     Attribute,
     AttributeSet,
+    Capability,
     FootprintsSpell,
     ItemAttribute,
+    ItemSlot,
+    Lenient,
     PaintSpell,
+    SkippedAttribute,
     Spell,
     TryFromIntAttributeValue,
 };
-use crate::error::InsertError;
+use crate::error::{AttributeValueError, InsertError, SpellApplicabilityError, SpellInsertError};
 use crate::serialize;
-use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ops::{BitAnd, Sub};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use serde::de::{self, SeqAccess, Visitor};
+use serde::de::{SeqAccess, Visitor};
 
 const SPELL_COUNT: usize = 2;
 
@@ -120,6 +123,78 @@ impl SpellSet {
             Some(spell2),
         ])
     }
+    
+    /// Same as `try_insert`, but also checks that the spell can be applied to an item in the given
+    /// slot with the given capabilities. Paint spells require the item to be
+    /// [`Capability::Paintable`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{SpellSet, Spell, ItemSlot, Capability, AttributeSet};
+    /// use tf2_enum::error::{SpellApplicabilityError, SpellInsertError};
+    /// 
+    /// let mut spells = SpellSet::new();
+    /// 
+    /// assert!(spells.try_insert_for(Spell::Exorcism, ItemSlot::Melee, &[]).is_ok());
+    /// assert_eq!(
+    ///     spells.try_insert_for(Spell::HeadlessHorseshoes, ItemSlot::Melee, &[]),
+    ///     Err(SpellInsertError::Applicability(SpellApplicabilityError::CosmeticsOnly)),
+    /// );
+    /// assert_eq!(spells.len(), 1);
+    /// 
+    /// let mut spells = SpellSet::new();
+    /// 
+    /// assert_eq!(
+    ///     spells.try_insert_for(Spell::DieJob, ItemSlot::Misc, &[]),
+    ///     Err(SpellInsertError::Applicability(SpellApplicabilityError::NotPaintable)),
+    /// );
+    /// assert!(spells.try_insert_for(Spell::DieJob, ItemSlot::Misc, &[Capability::Paintable]).is_ok());
+    /// ```
+    pub fn try_insert_for(
+        &mut self,
+        spell: Spell,
+        item_slot: ItemSlot,
+        capabilities: &[Capability],
+    ) -> Result<(), SpellInsertError> {
+        spell.check_applicable_to_item(item_slot, capabilities)?;
+        self.try_insert(spell)?;
+        Ok(())
+    }
+    
+    /// Gets the spells in this set which cannot be applied to an item in the given slot with the
+    /// given capabilities, along with the reason. Returns an empty `Vec` if all spells can be
+    /// applied.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{SpellSet, Spell, ItemSlot, Capability};
+    /// use tf2_enum::error::SpellApplicabilityError;
+    /// 
+    /// let spells = SpellSet::double(Spell::DieJob, Spell::Exorcism);
+    /// let conflicts = spells.conflicts_for(ItemSlot::Misc, &[Capability::Paintable]);
+    /// 
+    /// assert_eq!(conflicts, vec![(Spell::Exorcism, SpellApplicabilityError::WeaponsOnly)]);
+    /// ```
+    pub fn conflicts_for(
+        &self,
+        item_slot: ItemSlot,
+        capabilities: &[Capability],
+    ) -> Vec<(Spell, SpellApplicabilityError)> {
+        self
+            .into_iter()
+            .filter_map(|spell| {
+                spell.check_applicable_to_item(item_slot, capabilities)
+                    .err()
+                    .map(|error| (spell, error))
+            })
+            .collect()
+    }
+    
+    /// Checks whether all spells in this set can be applied to an item in the given slot with the
+    /// given capabilities.
+    pub fn is_applicable_to(&self, item_slot: ItemSlot, capabilities: &[Capability]) -> bool {
+        self.conflicts_for(item_slot, capabilities).is_empty()
+    }
 }
 
 impl AttributeSet for SpellSet {
@@ -497,81 +572,119 @@ impl<'de> Deserialize<'de> for SpellSet {
     where
         D: Deserializer<'de>,
     {
-        struct StrangePartSetVisitor;
+        struct SpellSetVisitor;
         
-        impl<'de> Visitor<'de> for StrangePartSetVisitor {
+        impl<'de> Visitor<'de> for SpellSetVisitor {
             type Value = SpellSet;
+            
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an array of maps with defindex, float_value")
+            }
+            
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                serialize::deserialize_attribute_set(
+                    seq,
+                    spell_from_attribute,
+                    serialize::strict_attribute,
+                )
+            }
+        }
+        
+        deserializer.deserialize_seq(SpellSetVisitor)
+    }
+}
 
+impl<'de> Deserialize<'de> for Lenient<SpellSet> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LenientSpellSetVisitor;
+        
+        impl<'de> Visitor<'de> for LenientSpellSetVisitor {
+            type Value = Lenient<SpellSet>;
+            
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("an array of maps with defindex, float_value")
             }
             
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
             where
                 A: SeqAccess<'de>,
             {
-                let mut set = Self::Value::new();
-                let mut defindex_map = HashSet::new();
+                let mut skipped = Vec::new();
+                let value = serialize::deserialize_attribute_set(
+                    seq,
+                    spell_from_attribute,
+                    |attribute, error| {
+                        skipped.push(SkippedAttribute { attribute, error });
+                        Ok(())
+                    },
+                )?;
                 
-                while let Some(map) = seq.next_element::<ItemAttribute>()? {
-                    if defindex_map.contains(&map.defindex) {
-                        // Skip if defindex is already in the set
-                        continue;
-                    }
-                    
-                    defindex_map.insert(map.defindex);
-                    
-                    match map.defindex {
-                        FootprintsSpell::DEFINDEX => {
-                            let float_value = map.float_value
-                                .ok_or_else(|| de::Error::missing_field("float_value"))?;
-                            let part = FootprintsSpell::try_from_attribute_float_value(
-                                    float_value
-                                )
-                                .ok_or_else(|| de::Error::custom(
-                                    "cannot convert from float_value"
-                                ))?;
-                            
-                            set.insert(part.into());
-                            continue;
-                        },
-                        PaintSpell::DEFINDEX => {
-                            let float_value = map.float_value
-                                .ok_or_else(|| de::Error::missing_field("float_value"))?;
-                            let part = PaintSpell::try_from_attribute_float_value(
-                                    float_value
-                                )
-                                .ok_or_else(|| de::Error::custom(
-                                    "cannot convert from float_value"
-                                ))?;
-                            
-                            set.insert(part.into());
-                            continue;
-                        },
-                        Spell::DEFINDEX_EXORCISM => {
-                            set.insert(Spell::Exorcism);
-                        },
-                        Spell::DEFINDEX_HALLOWEEN_FIRE => {
-                            set.insert(Spell::HalloweenFire);
-                        },
-                        Spell::DEFINDEX_VOICES_FROM_BELOW => {
-                            set.insert(Spell::VoicesFromBelow);
-                        },
-                        Spell::DEFINDEX_PUMPKIN_BOMBS => {
-                            set.insert(Spell::PumpkinBombs);
-                        },
-                        _ => continue,
-                    }
-                }
-                
-                Ok(set)
+                Ok(Lenient { value, skipped })
             }
         }
         
-        deserializer.deserialize_seq(StrangePartSetVisitor)
+        deserializer.deserialize_seq(LenientSpellSetVisitor)
     }
 }
+
+/// Converts an attribute into a spell. Returns `None` if the attribute is not for a spell.
+fn spell_from_attribute(attribute: &ItemAttribute) -> Result<Option<Spell>, AttributeValueError> {
+    let float_value = || attribute.float_value.ok_or(AttributeValueError::MissingFloatValue);
     
+    match attribute.defindex {
+        FootprintsSpell::DEFINDEX => FootprintsSpell::try_from_attribute_float_value(float_value()?)
+            .map(|spell| Some(spell.into()))
+            .ok_or(AttributeValueError::UnknownValue),
+        PaintSpell::DEFINDEX => PaintSpell::try_from_attribute_float_value(float_value()?)
+            .map(|spell| Some(spell.into()))
+            .ok_or(AttributeValueError::UnknownValue),
+        Spell::DEFINDEX_EXORCISM => Ok(Some(Spell::Exorcism)),
+        Spell::DEFINDEX_HALLOWEEN_FIRE => Ok(Some(Spell::HalloweenFire)),
+        Spell::DEFINDEX_VOICES_FROM_BELOW => Ok(Some(Spell::VoicesFromBelow)),
+        Spell::DEFINDEX_PUMPKIN_BOMBS => Ok(Some(Spell::PumpkinBombs)),
+        _ => Ok(None),
+    }
+}
+
+/// Stored as `text[]`, sorted so that equal sets are stored identically.
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Type<::sqlx::Postgres> for SpellSet {
+    fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+        <Vec<Spell> as ::sqlx::Type<::sqlx::Postgres>>::type_info()
+    }
+    
+    fn compatible(ty: &::sqlx::postgres::PgTypeInfo) -> bool {
+        <Vec<Spell> as ::sqlx::Type<::sqlx::Postgres>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<'r> ::sqlx::Decode<'r, ::sqlx::Postgres> for SpellSet {
+    fn decode(
+        value: <::sqlx::Postgres as ::sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+        let spells = <Vec<Spell> as ::sqlx::Decode<'r, ::sqlx::Postgres>>::decode(value)?;
+        
+        Ok(crate::serialize::try_collect_attribute_set(spells)?)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Encode<'_, ::sqlx::Postgres> for SpellSet {
+    fn encode_by_ref(&self, buf: &mut ::sqlx::postgres::PgArgumentBuffer) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let mut spells = self.into_iter().collect::<Vec<_>>();
+        
+        spells.sort();
+        <Vec<Spell> as ::sqlx::Encode<'_, ::sqlx::Postgres>>::encode(spells, buf)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -583,10 +696,64 @@ mod tests {
         spell_set.insert(Spell::Exorcism);
         spell_set.insert(Spell::HeadlessHorseshoes);
         let serialized = serde_json::to_string(&spell_set).unwrap();
-                
+        
         assert_eq!(serialized, r#"[{"defindex":1009,"value":1065353216,"float_value":1},{"defindex":1005,"value":1073741824,"float_value":2}]"#);
     }
     
+    #[test]
+    fn deserializes_lenient() {
+        let json = r#"[
+            {"defindex":1009,"float_value":1},
+            {"defindex":1004,"float_value":99},
+            {"defindex":1005},
+            {"defindex":1004,"float_value":1}
+        ]"#;
+        
+        assert!(serde_json::from_str::<SpellSet>(json).is_err());
+        
+        let spell_set: Lenient<SpellSet> = serde_json::from_str(json).unwrap();
+        
+        // The invalid 1004 is skipped without hiding the valid one after it.
+        assert_eq!(spell_set.value, SpellSet::double(Spell::Exorcism, Spell::ChromaticCorruption));
+        assert_eq!(spell_set.skipped.len(), 2);
+        assert_eq!(spell_set.skipped[0].error, AttributeValueError::UnknownValue);
+        assert_eq!(spell_set.skipped[1].attribute.defindex, FootprintsSpell::DEFINDEX);
+        assert_eq!(spell_set.skipped[1].error, AttributeValueError::MissingFloatValue);
+        assert_eq!(serde_json::to_string(&spell_set).unwrap(), serde_json::to_string(&spell_set.value).unwrap());
+    }
+    
+    #[cfg(feature = "sqlx-postgres-0_8")]
+    #[test]
+    fn decodes_postgres_spells() {
+        use crate::serialize::try_collect_attribute_set;
+        
+        let decoded: SpellSet = try_collect_attribute_set([Spell::HalloweenFire, Spell::Exorcism]).unwrap();
+        
+        assert_eq!(decoded, SpellSet::double(Spell::Exorcism, Spell::HalloweenFire));
+        assert_eq!(
+            try_collect_attribute_set::<SpellSet, _>([Spell::Exorcism, Spell::Exorcism]),
+            Err(InsertError::Duplicate),
+        );
+    }
+    
+    #[cfg(feature = "sqlx-postgres-0_8")]
+    #[test]
+    fn encodes_postgres_in_canonical_order() {
+        use sqlx::Encode;
+        
+        let encode = |spell_set: SpellSet| {
+            let mut buf = sqlx::postgres::PgArgumentBuffer::default();
+            
+            assert!(matches!(spell_set.encode_by_ref(&mut buf).unwrap(), sqlx::encode::IsNull::No));
+            buf.to_vec()
+        };
+        
+        assert_eq!(
+            encode(SpellSet::double(Spell::Exorcism, Spell::HalloweenFire)),
+            encode(SpellSet::double(Spell::HalloweenFire, Spell::Exorcism)),
+        );
+    }
+    
     #[test]
     fn base_methods() {
         let mut spell_set = SpellSet::new();
@@ -693,6 +860,36 @@ mod tests {
         ]));
     }
     
+    #[test]
+    fn inserts_for_item_slot() {
+        let mut spells = SpellSet::new();
+        
+        assert!(spells.try_insert_for(Spell::TeamSpiritFootprints, ItemSlot::Misc, &[]).is_ok());
+        assert_eq!(
+            spells.try_insert_for(Spell::HeadlessHorseshoes, ItemSlot::Misc, &[]),
+            Err(SpellInsertError::Insert(InsertError::Duplicate)),
+        );
+        assert_eq!(
+            spells.try_insert_for(Spell::PumpkinBombs, ItemSlot::Misc, &[]),
+            Err(SpellInsertError::Applicability(SpellApplicabilityError::WeaponsOnly)),
+        );
+        assert!(spells.is_applicable_to(ItemSlot::Misc, &[]));
+        assert!(!spells.is_applicable_to(ItemSlot::Primary, &[]));
+    }
+    
+    #[test]
+    fn inserts_paint_spells_only_for_paintable_items() {
+        let mut spells = SpellSet::new();
+        
+        assert_eq!(
+            spells.try_insert_for(Spell::ChromaticCorruption, ItemSlot::Misc, &[Capability::CanCraftCount]),
+            Err(SpellInsertError::Applicability(SpellApplicabilityError::NotPaintable)),
+        );
+        assert!(spells.is_empty());
+        assert!(spells.try_insert_for(Spell::ChromaticCorruption, ItemSlot::Misc, &[Capability::Paintable]).is_ok());
+        assert_eq!(spells.first(), Some(&Spell::ChromaticCorruption));
+    }
+    
     #[test]
     fn stringify() {
         let spells = SpellSet::from([
@@ -81,3 +81,217 @@ macro_rules! impl_sqlx_enum_repr_postgres {
         }
     };
 }
+
+/// Macro to implement sqlx::Type, Decode, and Encode for a type that implements FromStr and
+/// Display as SQLite TEXT.
+#[macro_export]
+macro_rules! impl_sqlx_enum_display_sqlite {
+    ($t:ty) => {
+        impl ::sqlx::Type<::sqlx::Sqlite> for $t {
+            fn type_info() -> ::sqlx::sqlite::SqliteTypeInfo {
+                <&str as ::sqlx::Type<::sqlx::Sqlite>>::type_info()
+            }
+
+            fn compatible(ty: &::sqlx::sqlite::SqliteTypeInfo) -> bool {
+                <&str as ::sqlx::Type<::sqlx::Sqlite>>::compatible(ty)
+            }
+        }
+
+        impl<'r> ::sqlx::Decode<'r, ::sqlx::Sqlite> for $t {
+            fn decode(
+                value: <::sqlx::Sqlite as ::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<$t, Box<dyn std::error::Error + 'static + Send + Sync>> {
+                Ok(<&str as ::sqlx::Decode<'r, ::sqlx::Sqlite>>::decode(value)?.parse()?)
+            }
+        }
+
+        impl<'q> ::sqlx::Encode<'q, ::sqlx::Sqlite> for $t {
+            fn encode_by_ref(&self, buf: &mut <::sqlx::Sqlite as ::sqlx::Database>::ArgumentBuffer<'q>) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                <String as ::sqlx::Encode<'q, ::sqlx::Sqlite>>::encode(self.to_string(), buf)
+            }
+        }
+    };
+}
+
+/// Macro to implement sqlx::Type, Decode, and Encode for enums with repr(u32) as i32 (SQLite
+/// INTEGER).
+#[macro_export]
+macro_rules! impl_sqlx_enum_repr_sqlite {
+    ($t:ty) => {
+        impl ::sqlx::Type<::sqlx::Sqlite> for $t {
+            fn type_info() -> ::sqlx::sqlite::SqliteTypeInfo {
+                <i32 as ::sqlx::Type<::sqlx::Sqlite>>::type_info()
+            }
+
+            fn compatible(ty: &::sqlx::sqlite::SqliteTypeInfo) -> bool {
+                <i32 as ::sqlx::Type<::sqlx::Sqlite>>::compatible(ty)
+            }
+        }
+
+        impl<'r> ::sqlx::Decode<'r, ::sqlx::Sqlite> for $t {
+            fn decode(
+                value: <::sqlx::Sqlite as ::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<$t, Box<dyn std::error::Error + 'static + Send + Sync>> {
+                let v = <i32 as ::sqlx::Decode<'r, ::sqlx::Sqlite>>::decode(value)?;
+
+                <$t as ::std::convert::TryFrom<u32>>::try_from(v as u32)
+                    .map_err(|_| format!("Invalid enum value for {}: {}", stringify!($t), v).into())
+            }
+        }
+
+        impl<'q> ::sqlx::Encode<'q, ::sqlx::Sqlite> for $t {
+            fn encode_by_ref(&self, buf: &mut <::sqlx::Sqlite as ::sqlx::Database>::ArgumentBuffer<'q>) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                let v = *self as u32 as i32;
+                <i32 as ::sqlx::Encode<'q, ::sqlx::Sqlite>>::encode(v, buf)
+            }
+        }
+    };
+}
+
+/// Macro to implement sqlx::Type, Decode, and Encode for a type that implements FromStr and
+/// Display as MySQL TEXT.
+#[macro_export]
+macro_rules! impl_sqlx_enum_display_mysql {
+    ($t:ty) => {
+        impl ::sqlx::Type<::sqlx::MySql> for $t {
+            fn type_info() -> ::sqlx::mysql::MySqlTypeInfo {
+                <&str as ::sqlx::Type<::sqlx::MySql>>::type_info()
+            }
+
+            fn compatible(ty: &::sqlx::mysql::MySqlTypeInfo) -> bool {
+                <&str as ::sqlx::Type<::sqlx::MySql>>::compatible(ty)
+            }
+        }
+
+        impl<'r> ::sqlx::Decode<'r, ::sqlx::MySql> for $t {
+            fn decode(
+                value: <::sqlx::MySql as ::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<$t, Box<dyn std::error::Error + 'static + Send + Sync>> {
+                Ok(<&str as ::sqlx::Decode<'r, ::sqlx::MySql>>::decode(value)?.parse()?)
+            }
+        }
+
+        impl ::sqlx::Encode<'_, ::sqlx::MySql> for $t {
+            fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                let s = self.to_string();
+                <&str as ::sqlx::Encode<'_, ::sqlx::MySql>>::encode(&s, buf)
+            }
+        }
+    };
+}
+
+/// Macro to implement sqlx::Type, Decode, and Encode for enums with repr(u32) as i32 (MySQL
+/// INT).
+#[macro_export]
+macro_rules! impl_sqlx_enum_repr_mysql {
+    ($t:ty) => {
+        impl ::sqlx::Type<::sqlx::MySql> for $t {
+            fn type_info() -> ::sqlx::mysql::MySqlTypeInfo {
+                <i32 as ::sqlx::Type<::sqlx::MySql>>::type_info()
+            }
+
+            fn compatible(ty: &::sqlx::mysql::MySqlTypeInfo) -> bool {
+                <i32 as ::sqlx::Type<::sqlx::MySql>>::compatible(ty)
+            }
+        }
+
+        impl<'r> ::sqlx::Decode<'r, ::sqlx::MySql> for $t {
+            fn decode(
+                value: <::sqlx::MySql as ::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<$t, Box<dyn std::error::Error + 'static + Send + Sync>> {
+                let v = <i32 as ::sqlx::Decode<'r, ::sqlx::MySql>>::decode(value)?;
+
+                <$t as ::std::convert::TryFrom<u32>>::try_from(v as u32)
+                    .map_err(|_| format!("Invalid enum value for {}: {}", stringify!($t), v).into())
+            }
+        }
+
+        impl ::sqlx::Encode<'_, ::sqlx::MySql> for $t {
+            fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                let v = *self as u32 as i32;
+                <i32 as ::sqlx::Encode<'_, ::sqlx::MySql>>::encode(v, buf)
+            }
+        }
+    };
+}
+
+#[cfg(all(test, feature = "sqlx-sqlite-0_8"))]
+mod tests {
+    use crate::{Class, ItemSlot, MaybeKnown, Origin, Paint, Quality, Spell};
+    use sqlx::{Connection, Row, SqliteConnection};
+    
+    async fn connect() -> SqliteConnection {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        
+        sqlx::query("CREATE TABLE items (quality INTEGER, paint INTEGER, class TEXT, spell TEXT, origin INTEGER)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        conn
+    }
+    
+    #[tokio::test]
+    async fn round_trips_enums() {
+        let mut conn = connect().await;
+        
+        sqlx::query("INSERT INTO items (quality, paint, class, spell, origin) VALUES (?, ?, ?, ?, ?)")
+            .bind(Quality::Strange)
+            .bind(Paint::AustraliumGold)
+            .bind(Class::Scout)
+            .bind(Spell::HalloweenFire)
+            .bind(MaybeKnown::<Origin>::Unknown(9999))
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        
+        let row = sqlx::query("SELECT quality, paint, class, spell, origin FROM items")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        
+        assert_eq!(row.get::<Quality, _>("quality"), Quality::Strange);
+        assert_eq!(row.get::<Paint, _>("paint"), Paint::AustraliumGold);
+        assert_eq!(row.get::<Class, _>("class"), Class::Scout);
+        assert_eq!(row.get::<Spell, _>("spell"), Spell::HalloweenFire);
+        assert_eq!(row.get::<MaybeKnown<Origin>, _>("origin"), MaybeKnown::Unknown(9999));
+    }
+    
+    #[tokio::test]
+    async fn encodes_as_text_or_integer() {
+        let mut conn = connect().await;
+        
+        sqlx::query("INSERT INTO items (quality, class) VALUES (?, ?)")
+            .bind(Quality::Strange)
+            .bind(Class::Scout)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        
+        let (quality, class): (i32, String) = sqlx::query_as("SELECT quality, class FROM items")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        
+        assert_eq!(quality, Quality::Strange as i32);
+        assert_eq!(class, Class::Scout.to_string());
+    }
+    
+    #[tokio::test]
+    async fn rejects_invalid_values() {
+        let mut conn = connect().await;
+        
+        sqlx::query("INSERT INTO items (quality, class) VALUES (9999, 'Not a slot')")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        
+        let row = sqlx::query("SELECT quality, class FROM items")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        
+        assert!(row.try_get::<Quality, _>("quality").is_err());
+        assert!(row.try_get::<ItemSlot, _>("class").is_err());
+        assert_eq!(row.get::<MaybeKnown<Quality>, _>("quality"), MaybeKnown::Unknown(9999));
+    }
+}
@@ -2,17 +2,21 @@
 use crate::{
     Attributes,
     AttributeDef,
+    Class,
     EffectType,
     HasItemDefindex,
+    ItemSlot,
     KillEaterScoreType,
+    StockWeapon,
     TryFromIntAttributeValue,
 };
-use crate::error::TryFromPrimitiveError;
+use crate::error::{StrangePartApplicabilityError, TryFromPrimitiveError};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
 
 // Avoid repeating strings
+// codegen:start names
 const STR_SCOUTS_KILLED: &str = "Strange Part: Scouts Killed";
 const STR_SNIPERS_KILLED: &str = "Strange Part: Snipers Killed";
 const STR_SOLDIERS_KILLED: &str = "Strange Part: Soldiers Killed";
@@ -65,6 +69,7 @@ const STR_TAUNTING_PLAYER_KILLS: &str = "Strange Part: Taunting Player Kills";
 const STR_NOT_CRIT_NOR_MINI_CRIT_KILLS: &str = "Strange Part: Not Crit nor MiniCrit Kills";
 const STR_PLAYER_HITS: &str = "Strange Part: Player Hits";
 const STR_ASSISTS: &str = "Strange Cosmetic Part: Assists";
+// codegen:end names
 
 /// Strange part. `repr` values are mapped to their `kill_eater_score_type` attribute value. Strings
 /// are the name of the `kill_eater_score_type`, **not** the name of the strange part.
@@ -82,14 +87,19 @@ const STR_ASSISTS: &str = "Strange Cosmetic Part: Assists";
     Deserialize_repr,
     EnumString,
     EnumIter,
+    EnumMessage,
     EnumCount,
     TryFromPrimitive,
     IntoPrimitive,
 )]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
 #[repr(u32)]
 #[non_exhaustive]
 #[allow(missing_docs)]
 pub enum StrangePart {
+    // codegen:start variants
     #[strum(serialize = "Scouts Killed")]
     ScoutsKilled = 10,
     #[strum(serialize = "Snipers Killed")]
@@ -196,6 +206,7 @@ pub enum StrangePart {
     PlayerHits = 94,
     #[strum(serialize = "Assists")]
     Assists = 95,
+    // codegen:end variants
 }
 
 impl StrangePart {
@@ -219,6 +230,7 @@ impl StrangePart {
     /// Gets the name of the strange part for this [`StrangePart`].
     pub fn strange_part_name(&self) -> &'static str {
         match self {
+            // codegen:start strange_part_name
             Self::ScoutsKilled => STR_SCOUTS_KILLED,
             Self::SnipersKilled => STR_SNIPERS_KILLED,
             Self::SoldiersKilled => STR_SOLDIERS_KILLED,
@@ -271,12 +283,14 @@ impl StrangePart {
             Self::NotCritNorMiniCritKills => STR_NOT_CRIT_NOR_MINI_CRIT_KILLS,
             Self::PlayerHits => STR_PLAYER_HITS,
             Self::Assists => STR_ASSISTS,
+            // codegen:end strange_part_name
         }
     }
     
     /// Gets the related [`StrangePart`] by its strange part name, if it exists.
     pub fn from_strange_part_name(name: &str) -> Option<StrangePart> {
         match name {
+            // codegen:start from_strange_part_name
             STR_SCOUTS_KILLED => Some(Self::ScoutsKilled),
             STR_SNIPERS_KILLED => Some(Self::SnipersKilled),
             STR_SOLDIERS_KILLED => Some(Self::SoldiersKilled),
@@ -329,13 +343,137 @@ impl StrangePart {
             STR_NOT_CRIT_NOR_MINI_CRIT_KILLS => Some(Self::NotCritNorMiniCritKills),
             STR_PLAYER_HITS => Some(Self::PlayerHits),
             STR_ASSISTS => Some(Self::Assists),
+            // codegen:end from_strange_part_name
             _ => None,
         }
     }
     
     /// Is this strange part for cosmetics?
     pub fn is_cosmetic_part(&self) -> bool {
-        matches!(self, Self::Kills | Self::FullHealthKills | Self::Assists | Self::FreezecamTauntAppearances)
+        matches!(
+            self,
+            Self::Kills |
+            Self::FullHealthKills |
+            Self::Assists |
+            Self::FreezecamTauntAppearances |
+            Self::FiresSurvived
+        )
+    }
+    
+    /// Gets the item slots this [`StrangePart`] can be applied to.
+    pub fn applicable_item_slots(&self) -> &'static [ItemSlot] {
+        match self {
+            _ if self.is_cosmetic_part() => ItemSlot::COSMETICS,
+            Self::ProjectilesReflected => &[ItemSlot::Primary],
+            Self::TeammatesExtinguished => &[ItemSlot::Primary, ItemSlot::Secondary],
+            Self::HeadshotKills => &[ItemSlot::Primary, ItemSlot::Secondary],
+            Self::AlliedHealingDone => &[ItemSlot::Secondary],
+            _ => ItemSlot::WEAPONS,
+        }
+    }
+    
+    /// Gets the item slots this [`StrangePart`] can be applied to for items used by the given
+    /// class. This narrows [`StrangePart::applicable_item_slots`] for strange parts where the slot
+    /// depends on the class, e.g. [`StrangePart::HeadshotKills`] can be applied to Sniper
+    /// primaries and Spy secondaries.
+    pub fn applicable_item_slots_for(&self, class: Class) -> &'static [ItemSlot] {
+        match (self, class) {
+            (Self::HeadshotKills, Class::Sniper) => &[ItemSlot::Primary],
+            (Self::HeadshotKills, Class::Spy) => &[ItemSlot::Secondary],
+            _ => self.applicable_item_slots(),
+        }
+    }
+    
+    /// Gets the classes this [`StrangePart`] is restricted to. Returns `None` if the strange part
+    /// can be applied to items for any class.
+    pub fn applicable_classes(&self) -> Option<&'static [Class]> {
+        match self {
+            Self::ProjectilesReflected |
+            Self::TeammatesExtinguished => Some(&[Class::Pyro]),
+            Self::HeadshotKills => Some(&[Class::Sniper, Class::Spy]),
+            Self::AlliedHealingDone => Some(&[Class::Medic]),
+            Self::KillsWhileExplosiveJumping => Some(&[Class::Soldier, Class::Demoman]),
+            Self::SappersRemoved => Some(&[Class::Spy, Class::Engineer]),
+            // Mann vs. Machine parts such as RobotScoutsDestroyed count robots killed by any
+            // weapon, so they have no class restriction.
+            _ => None,
+        }
+    }
+    
+    /// Checks whether this [`StrangePart`] can be applied to an item in the given slot used by the
+    /// given classes, returning the reason if it can't. An empty slice of classes means the item
+    /// is usable by all classes.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{StrangePart, ItemSlot, Class};
+    /// use tf2_enum::error::StrangePartApplicabilityError;
+    /// 
+    /// assert_eq!(
+    ///     StrangePart::ProjectilesReflected.check_applicable_to(ItemSlot::Primary, &[Class::Scout]),
+    ///     Err(StrangePartApplicabilityError::Classes(&[Class::Pyro])),
+    /// );
+    /// assert_eq!(
+    ///     StrangePart::Assists.check_applicable_to(ItemSlot::Melee, &[]),
+    ///     Err(StrangePartApplicabilityError::CosmeticsOnly),
+    /// );
+    /// ```
+    pub fn check_applicable_to(
+        &self,
+        item_slot: ItemSlot,
+        classes: &[Class],
+    ) -> Result<(), StrangePartApplicabilityError> {
+        let item_slots = self.applicable_item_slots();
+        
+        if !item_slots.contains(&item_slot) {
+            return Err(if item_slots == ItemSlot::COSMETICS {
+                StrangePartApplicabilityError::CosmeticsOnly
+            } else if !item_slot.is_weapon() {
+                StrangePartApplicabilityError::WeaponsOnly
+            } else {
+                StrangePartApplicabilityError::ItemSlots(item_slots)
+            });
+        }
+        
+        if let Some(applicable_classes) = self.applicable_classes() {
+            if classes.is_empty() {
+                return Ok(());
+            }
+            
+            let mut matching_classes = classes
+                .iter()
+                .filter(|class| applicable_classes.contains(class))
+                .peekable();
+            let Some(first_class) = matching_classes.peek().copied() else {
+                return Err(StrangePartApplicabilityError::Classes(applicable_classes));
+            };
+            let item_slots = self.applicable_item_slots_for(*first_class);
+            
+            if !matching_classes.any(|class| self.applicable_item_slots_for(*class).contains(&item_slot)) {
+                return Err(StrangePartApplicabilityError::ItemSlots(item_slots));
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Checks whether this [`StrangePart`] can be applied to an item in the given slot used by the
+    /// given classes. An empty slice of classes means the item is usable by all classes.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{StrangePart, ItemSlot, Class};
+    /// 
+    /// assert!(StrangePart::HeadshotKills.is_applicable_to(ItemSlot::Primary, &[Class::Sniper]));
+    /// assert!(!StrangePart::HeadshotKills.is_applicable_to(ItemSlot::Melee, &[Class::Sniper]));
+    /// ```
+    pub fn is_applicable_to(&self, item_slot: ItemSlot, classes: &[Class]) -> bool {
+        self.check_applicable_to(item_slot, classes).is_ok()
+    }
+    
+    /// Checks whether this [`StrangePart`] can be applied to a [`StockWeapon`].
+    pub fn is_applicable_to_stock_weapon(&self, stock_weapon: StockWeapon) -> bool {
+        self.is_applicable_to(stock_weapon.item_slot(), stock_weapon.used_by_classes())
     }
     
     /// Converts this [`StrangePart`] into its related [`KillEaterScoreType`], if it exists.
@@ -398,6 +536,7 @@ impl HasItemDefindex for StrangePart {
     /// Gets the `defindex` for the [`StrangePart`].
     fn defindex(&self) -> u32 {
         match self {
+            // codegen:start defindex
             Self::ScoutsKilled => 6003,
             Self::SnipersKilled => 6005,
             Self::SoldiersKilled => 6002,
@@ -450,12 +589,14 @@ impl HasItemDefindex for StrangePart {
             Self::NotCritNorMiniCritKills => 6063,
             Self::PlayerHits => 6064,
             Self::Assists => 6065,
+            // codegen:end defindex
         }
     }
     
     /// Converts a `defindex` into its related [`StrangePart`], if it exists.
     fn from_defindex(defindex: u32) -> Option<Self> {
         match defindex {
+            // codegen:start from_defindex
             6003 => Some(Self::ScoutsKilled),
             6005 => Some(Self::SnipersKilled),
             6002 => Some(Self::SoldiersKilled),
@@ -508,6 +649,7 @@ impl HasItemDefindex for StrangePart {
             6063 => Some(Self::NotCritNorMiniCritKills),
             6064 => Some(Self::PlayerHits),
             6065 => Some(Self::Assists),
+            // codegen:end from_defindex
             _ => None,
         }
     }
@@ -564,4 +706,59 @@ mod tests {
     fn attribute_slices_are_equal_length() {
         assert_eq!(StrangePart::DEFINDEX.len(), StrangePart::ATTRIBUTES.len());
     }
+    
+    #[test]
+    fn checks_headshot_kills_by_class() {
+        // The Ambassador is a Spy secondary.
+        assert!(StrangePart::HeadshotKills.is_applicable_to(ItemSlot::Secondary, &[Class::Spy]));
+        assert!(StrangePart::HeadshotKills.is_applicable_to(ItemSlot::Primary, &[Class::Sniper]));
+        assert_eq!(
+            StrangePart::HeadshotKills.check_applicable_to(ItemSlot::Secondary, &[Class::Sniper]),
+            Err(StrangePartApplicabilityError::ItemSlots(&[ItemSlot::Primary])),
+        );
+        assert_eq!(
+            StrangePart::HeadshotKills.check_applicable_to(ItemSlot::Primary, &[Class::Spy]),
+            Err(StrangePartApplicabilityError::ItemSlots(&[ItemSlot::Secondary])),
+        );
+        assert_eq!(
+            StrangePart::HeadshotKills.check_applicable_to(ItemSlot::Primary, &[Class::Scout]),
+            Err(StrangePartApplicabilityError::Classes(&[Class::Sniper, Class::Spy])),
+        );
+    }
+    
+    #[test]
+    fn fires_survived_is_cosmetic_part() {
+        assert!(StrangePart::FiresSurvived.is_cosmetic_part());
+        assert!(StrangePart::FiresSurvived.is_applicable_to(ItemSlot::Misc, &[]));
+    }
+    
+    #[test]
+    fn robot_parts_apply_to_any_weapon() {
+        assert_eq!(StrangePart::RobotScoutsDestroyed.applicable_classes(), None);
+        assert!(StrangePart::RobotScoutsDestroyed.is_applicable_to_stock_weapon(StockWeapon::Bat));
+        assert!(StrangePart::RobotScoutsDestroyed.is_applicable_to_stock_weapon(StockWeapon::MediGun));
+        assert_eq!(
+            StrangePart::RobotScoutsDestroyed.check_applicable_to(ItemSlot::Misc, &[]),
+            Err(StrangePartApplicabilityError::WeaponsOnly),
+        );
+    }
+    
+    #[test]
+    fn checks_applicability() {
+        assert!(StrangePart::ScoutsKilled.is_applicable_to(ItemSlot::Melee, &[Class::Heavy]));
+        assert!(StrangePart::Kills.is_applicable_to(ItemSlot::Misc, &[]));
+        assert!(StrangePart::SappersRemoved.is_applicable_to_stock_weapon(StockWeapon::Wrench));
+        assert!(!StrangePart::SappersRemoved.is_applicable_to_stock_weapon(StockWeapon::Bat));
+        assert!(StrangePart::KillsWhileExplosiveJumping.is_applicable_to_stock_weapon(StockWeapon::Shotgun));
+        assert!(StrangePart::AlliedHealingDone.is_applicable_to_stock_weapon(StockWeapon::MediGun));
+        assert!(!StrangePart::AlliedHealingDone.is_applicable_to_stock_weapon(StockWeapon::Bonesaw));
+        assert_eq!(
+            StrangePart::DamageDealt.check_applicable_to(ItemSlot::Misc, &[]),
+            Err(StrangePartApplicabilityError::WeaponsOnly),
+        );
+        assert_eq!(
+            StrangePart::AlliedHealingDone.check_applicable_to(ItemSlot::Primary, &[Class::Medic]),
+            Err(StrangePartApplicabilityError::ItemSlots(&[ItemSlot::Secondary])),
+        );
+    }
 }
@@ -4,17 +4,18 @@ use crate::{
     Attributes,
     AttributeSet,
     ItemAttribute,
+    Lenient,
+    SkippedAttribute,
     StrangePart,
     TryFromIntAttributeValue,
 };
-use crate::error::InsertError;
+use crate::error::{AttributeValueError, InsertError};
 use crate::serialize;
-use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ops::{BitAnd, Sub};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use serde::de::{self, SeqAccess, Visitor};
+use serde::de::{SeqAccess, Visitor};
 
 const STRANGE_PART_COUNT: usize = 3;
 
@@ -485,7 +486,7 @@ pub struct StrangePartSetIterator {
 
 impl Iterator for StrangePartSetIterator {
     type Item = StrangePart;
-
+    
     fn next(&mut self) -> Option<Self::Item> {
         let iter = self.inner.by_ref();
         
@@ -548,50 +549,109 @@ impl<'de> Deserialize<'de> for StrangePartSet {
         
         impl<'de> Visitor<'de> for StrangePartSetVisitor {
             type Value = StrangePartSet;
+            
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an array of maps with defindex, float_value")
+            }
+            
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                serialize::deserialize_attribute_set(
+                    seq,
+                    strange_part_from_attribute,
+                    serialize::strict_attribute,
+                )
+            }
+        }
+        
+        deserializer.deserialize_seq(StrangePartSetVisitor)
+    }
+}
 
+impl<'de> Deserialize<'de> for Lenient<StrangePartSet> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LenientStrangePartSetVisitor;
+        
+        impl<'de> Visitor<'de> for LenientStrangePartSetVisitor {
+            type Value = Lenient<StrangePartSet>;
+            
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("an array of maps with defindex, float_value")
             }
             
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
             where
                 A: SeqAccess<'de>,
             {
-                let mut set = Self::Value::new();
-                let mut defindex_map = HashSet::new();
-                
-                while let Some(map) = seq.next_element::<ItemAttribute>()? {
-                    if !<Self::Value as AttributeSet>::Item::DEFINDEX.contains(&map.defindex) {
-                        // Skip if defindex is not for a score type
-                        continue;
-                    }
-                    
-                    if defindex_map.contains(&map.defindex) {
-                        // Skip if defindex is already in the set
-                        continue;
-                    }
-                    
-                    defindex_map.insert(map.defindex);
-                    
-                    let float_value = map.float_value
-                        .ok_or_else(|| de::Error::missing_field(
-                            "float_value"
-                        ))?;
-                    let part = <Self::Value as AttributeSet>::Item::try_from_attribute_float_value(
-                            float_value
-                        )
-                        .ok_or_else(|| de::Error::custom(
-                            "cannot convert from float_value"
-                        ))?;
-                    
-                    set.insert(part);
-                }
+                let mut skipped = Vec::new();
+                let value = serialize::deserialize_attribute_set(
+                    seq,
+                    strange_part_from_attribute,
+                    |attribute, error| {
+                        skipped.push(SkippedAttribute { attribute, error });
+                        Ok(())
+                    },
+                )?;
                 
-                Ok(set)
+                Ok(Lenient { value, skipped })
             }
         }
         
-        deserializer.deserialize_seq(StrangePartSetVisitor)
+        deserializer.deserialize_seq(LenientStrangePartSetVisitor)
+    }
+}
+
+/// Converts an attribute into a strange part. Returns `None` if the attribute is not for a score
+/// type.
+fn strange_part_from_attribute(
+    attribute: &ItemAttribute,
+) -> Result<Option<StrangePart>, AttributeValueError> {
+    if !StrangePart::DEFINDEX.contains(&attribute.defindex) {
+        return Ok(None);
+    }
+    
+    let float_value = attribute.float_value.ok_or(AttributeValueError::MissingFloatValue)?;
+    
+    StrangePart::try_from_attribute_float_value(float_value)
+        .map(Some)
+        .ok_or(AttributeValueError::UnknownValue)
+}
+
+/// Stored as `int4[]`, sorted so that equal sets are stored identically.
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Type<::sqlx::Postgres> for StrangePartSet {
+    fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+        <Vec<StrangePart> as ::sqlx::Type<::sqlx::Postgres>>::type_info()
+    }
+    
+    fn compatible(ty: &::sqlx::postgres::PgTypeInfo) -> bool {
+        <Vec<StrangePart> as ::sqlx::Type<::sqlx::Postgres>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl<'r> ::sqlx::Decode<'r, ::sqlx::Postgres> for StrangePartSet {
+    fn decode(
+        value: <::sqlx::Postgres as ::sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+        let strange_parts = <Vec<StrangePart> as ::sqlx::Decode<'r, ::sqlx::Postgres>>::decode(value)?;
+        
+        Ok(crate::serialize::try_collect_attribute_set(strange_parts)?)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres-0_8")]
+impl ::sqlx::Encode<'_, ::sqlx::Postgres> for StrangePartSet {
+    fn encode_by_ref(&self, buf: &mut ::sqlx::postgres::PgArgumentBuffer) -> Result<::sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let mut strange_parts = self.into_iter().collect::<Vec<_>>();
+        
+        strange_parts.sort_by_key(|strange_part| *strange_part as u32);
+        <Vec<StrangePart> as ::sqlx::Encode<'_, ::sqlx::Postgres>>::encode(strange_parts, buf)
     }
 }
 
@@ -613,6 +673,47 @@ mod tests {
         assert_eq!(json, expected);
     }
     
+    #[cfg(feature = "sqlx-postgres-0_8")]
+    #[test]
+    fn decodes_postgres_strange_parts() {
+        use crate::serialize::try_collect_attribute_set;
+        
+        let decoded: StrangePartSet = try_collect_attribute_set([StrangePart::TauntKills, StrangePart::CriticalKills]).unwrap();
+        
+        assert_eq!(decoded, StrangePartSet::double(StrangePart::CriticalKills, StrangePart::TauntKills));
+        assert_eq!(
+            try_collect_attribute_set::<StrangePartSet, _>([StrangePart::TauntKills, StrangePart::TauntKills]),
+            Err(InsertError::Duplicate),
+        );
+        assert_eq!(
+            try_collect_attribute_set::<StrangePartSet, _>([
+                StrangePart::TauntKills,
+                StrangePart::CriticalKills,
+                StrangePart::ScoutsKilled,
+                StrangePart::SnipersKilled,
+            ]),
+            Err(InsertError::Full),
+        );
+    }
+    
+    #[cfg(feature = "sqlx-postgres-0_8")]
+    #[test]
+    fn encodes_postgres_in_canonical_order() {
+        use sqlx::Encode;
+        
+        let encode = |strange_parts: StrangePartSet| {
+            let mut buf = sqlx::postgres::PgArgumentBuffer::default();
+            
+            assert!(matches!(strange_parts.encode_by_ref(&mut buf).unwrap(), sqlx::encode::IsNull::No));
+            buf.to_vec()
+        };
+        
+        assert_eq!(
+            encode(StrangePartSet::double(StrangePart::CriticalKills, StrangePart::TauntKills)),
+            encode(StrangePartSet::double(StrangePart::TauntKills, StrangePart::CriticalKills)),
+        );
+    }
+    
     #[test]
     fn deserializes() {
         // It doesn't need the "value" field to deserialize.
@@ -831,7 +932,7 @@ mod tests {
             Some(StrangePart::KillsWhileExplosiveJumping),
             Some(StrangePart::CriticalKills),
         ]);
-
+        
         assert_eq!(strange_parts.first(), Some(&StrangePart::TauntKills));
         assert_eq!(strange_parts.last(), Some(&StrangePart::CriticalKills));
     }
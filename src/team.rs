@@ -0,0 +1,100 @@
+use crate::{Attribute, Paint};
+use crate::econ_attributes::SetItemTintRgb2;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum::{Display, EnumCount, EnumIter, EnumMessage, EnumString};
+
+/// Team. `repr` values are mapped to the team numbers used by the game.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Display,
+    Serialize_repr,
+    Deserialize_repr,
+    EnumString,
+    EnumIter,
+    EnumMessage,
+    EnumCount,
+    TryFromPrimitive,
+    IntoPrimitive,
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "diesel-2", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel-2", diesel(sql_type = diesel::sql_types::Integer))]
+#[repr(u32)]
+#[allow(missing_docs)]
+pub enum Team {
+    Unassigned = 0,
+    Spectator = 1,
+    #[strum(serialize = "RED")]
+    Red = 2,
+    #[strum(serialize = "BLU")]
+    Blu = 3,
+}
+
+impl Team {
+    /// Checks if this is a playable team, i.e. RED or BLU.
+    pub fn is_playable(&self) -> bool {
+        matches!(self, Self::Red | Self::Blu)
+    }
+    
+    /// Gets the opposing team. Non-playable teams have no opposing team.
+    pub fn opponent(&self) -> Option<Self> {
+        match self {
+            Self::Red => Some(Self::Blu),
+            Self::Blu => Some(Self::Red),
+            _ => None,
+        }
+    }
+    
+    /// Gets the `defindex` of the paint attribute holding the color for this team. This is
+    /// "set_item_tint_rgb" (142) for RED and "set_item_tint_rgb_2" (261) for BLU. Non-playable
+    /// teams use the color for RED.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::Team;
+    /// 
+    /// assert_eq!(Team::Red.set_item_tint_rgb_defindex(), 142);
+    /// assert_eq!(Team::Blu.set_item_tint_rgb_defindex(), 261);
+    /// ```
+    pub fn set_item_tint_rgb_defindex(&self) -> u32 {
+        match self {
+            Self::Blu => SetItemTintRgb2::DEFINDEX,
+            _ => Paint::DEFINDEX,
+        }
+    }
+    
+    /// Gets the team associated with a paint attribute `defindex`, if it is one.
+    pub fn from_set_item_tint_rgb_defindex(defindex: u32) -> Option<Self> {
+        match defindex {
+            Paint::DEFINDEX => Some(Self::Red),
+            SetItemTintRgb2::DEFINDEX => Some(Self::Blu),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    
+    #[test]
+    fn displays_as_string() {
+        assert_eq!(Team::Red.to_string(), "RED");
+        assert_eq!(Team::from_str("BLU").unwrap(), Team::Blu);
+    }
+    
+    #[test]
+    fn converts_to_primitive() {
+        assert_eq!(Team::Blu as u32, 3);
+        assert_eq!(Team::try_from(1).unwrap(), Team::Spectator);
+    }
+}
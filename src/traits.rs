@@ -1,5 +1,7 @@
-use crate::{AttributeDef, AttributeValue, ItemAttribute};
+use crate::{AttributeDef, AttributeValue, ItemAttribute, Team};
+use crate::color::{self, ColorMatch};
 use crate::error::InsertError;
+use strum::IntoEnumIterator;
 
 /// Attribute values for an item attribute.
 pub trait Attribute: Sized {
@@ -129,6 +131,174 @@ pub trait Colored: Sized {
     fn from_color_str<S: AsRef<str>>(color: S) -> Option<Self> {
         Self::from_color(extract_color(color.as_ref())?)
     }
+    
+    /// Gets the colors compared against when finding the nearest match for a color. Definitions
+    /// associated with multiple colors, such as team paints, should include all of them.
+    fn match_colors(&self) -> Vec<u32> {
+        vec![self.color()]
+    }
+    
+    /// Gets the red, green, and blue components of the color.
+    #[inline]
+    fn rgb(&self) -> (u8, u8, u8) {
+        color::rgb(self.color())
+    }
+    
+    /// Gets the hue, saturation, and lightness of the color. See [`color::hsl`].
+    #[inline]
+    fn hsl(&self) -> (f32, f32, f32) {
+        color::hsl(self.color())
+    }
+    
+    /// Gets the CIE L\*a\*b\* values of the color. See [`color::lab`].
+    #[inline]
+    fn lab(&self) -> (f32, f32, f32) {
+        color::lab(self.color())
+    }
+    
+    /// Finds the definition with the color nearest to a hexadecimal color using a perceptual
+    /// distance (CIEDE2000). Unlike [`Colored::from_color`], this doesn't require an exact match.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Paint, Colored};
+    /// 
+    /// let nearest = Paint::from_color_nearest(0xFF6AB5).unwrap();
+    /// 
+    /// assert_eq!(nearest.value, Paint::PinkAsHell);
+    /// assert!(nearest.distance < 1.0);
+    /// 
+    /// // Team paints are matched against the colors for both teams.
+    /// let nearest = Paint::from_color_nearest(0x5885A2).unwrap();
+    /// 
+    /// assert_eq!(nearest.value, Paint::TeamSpirit);
+    /// assert_eq!(nearest.distance, 0.0);
+    /// ```
+    fn from_color_nearest(color: u32) -> Option<ColorMatch<Self>>
+    where
+        Self: IntoEnumIterator + Copy,
+    {
+        Self::iter()
+            .flat_map(|value| {
+                value
+                    .match_colors()
+                    .into_iter()
+                    .map(move |match_color| ColorMatch {
+                        value,
+                        color: match_color,
+                        distance: color::delta_e(color, match_color),
+                    })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+    
+    /// Same as [`Colored::from_color_nearest`], but accepts a hexadecimal color string.
+    fn from_color_str_nearest<S: AsRef<str>>(color: S) -> Option<ColorMatch<Self>>
+    where
+        Self: IntoEnumIterator + Copy,
+    {
+        Self::from_color_nearest(extract_color(color.as_ref())?)
+    }
+}
+
+/// Definitions which are associated with colors that can differ by team.
+pub trait TeamColored: Colored {
+    /// Gets the colors for both teams. The RED team appears first. For definitions which aren't
+    /// team-colored, the color will be the same.
+    fn colors(&self) -> (u32, u32);
+    
+    /// Gets the color for a team. Non-playable teams use the color for RED.
+    fn color_for(&self, team: Team) -> u32 {
+        let (red, blu) = self.colors();
+        
+        match team {
+            Team::Blu => blu,
+            _ => red,
+        }
+    }
+    
+    /// Attempts to convert a hexadecimal color for a team.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{Paint, Team, TeamColored};
+    /// 
+    /// assert_eq!(Paint::from_color_for(Team::Blu, 0x5885A2), Some(Paint::TeamSpirit));
+    /// assert_eq!(Paint::from_color_for(Team::Red, 0x5885A2), None);
+    /// ```
+    fn from_color_for(team: Team, color: u32) -> Option<Self>
+    where
+        Self: IntoEnumIterator,
+    {
+        Self::iter().find(|value| value.color_for(team) == color)
+    }
+    
+    /// Checks if the color differs by team.
+    fn has_team_colors(&self) -> bool {
+        self.color_for(Team::Red) != self.color_for(Team::Blu)
+    }
+}
+
+/// Definitions which may be associated with colors that can differ by team. Unlike [`Colored`]
+/// and [`TeamColored`], not every value has a known color.
+pub trait MaybeColored: Sized {
+    /// Gets the colors for both teams, if known. The RED team appears first. For definitions which
+    /// aren't team-colored, the color will be the same.
+    fn colors(&self) -> Option<(u32, u32)>;
+    
+    /// Gets the color, if known. For definitions with colors that differ by team, this is the
+    /// color for RED.
+    fn color(&self) -> Option<u32> {
+        self.colors().map(|(red, _)| red)
+    }
+    
+    /// Gets the color for a team, if known. Non-playable teams use the color for RED.
+    fn color_for(&self, team: Team) -> Option<u32> {
+        let (red, blu) = self.colors()?;
+        
+        match team {
+            Team::Blu => Some(blu),
+            _ => Some(red),
+        }
+    }
+    
+    /// Converts this into a hexademical color string in the format "#FFFFFF", if the color is
+    /// known.
+    fn color_string(&self) -> Option<String> {
+        self.color().map(|color| format!("#{color:06X}"))
+    }
+    
+    /// Attempts to convert a hexadecimal color.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_enum::{FootprintsSpell, MaybeColored};
+    /// 
+    /// assert_eq!(FootprintsSpell::from_color(0x808000), Some(FootprintsSpell::GangreenFootprints));
+    /// assert_eq!(FootprintsSpell::TeamSpiritFootprints.color(), None);
+    /// ```
+    fn from_color(color: u32) -> Option<Self>
+    where
+        Self: IntoEnumIterator,
+    {
+        Self::iter().find(|value| value.color() == Some(color))
+    }
+    
+    /// Attempts to convert a hexadecimal color string.
+    fn from_color_str<S: AsRef<str>>(color: S) -> Option<Self>
+    where
+        Self: IntoEnumIterator,
+    {
+        Self::from_color(extract_color(color.as_ref())?)
+    }
+    
+    /// Attempts to convert a hexadecimal color for a team.
+    fn from_color_for(team: Team, color: u32) -> Option<Self>
+    where
+        Self: IntoEnumIterator,
+    {
+        Self::iter().find(|value| value.color_for(team) == Some(color))
+    }
 }
 
 /// Definitions which are associated with an item defindex.
@@ -140,6 +310,15 @@ pub trait HasItemDefindex: Sized {
     fn from_defindex(defindex: u32) -> Option<Self>;
 }
 
+/// An entry in a [`Registry`][crate::Registry], looked up by its ID and name.
+pub trait RegistryEntry {
+    /// Gets the ID.
+    fn id(&self) -> u32;
+    
+    /// Gets the name.
+    fn name(&self) -> &str;
+}
+
 /// A fixed set of attributes.
 pub trait AttributeSet: Sized + Default {
     /// Max number of items.
@@ -0,0 +1,238 @@
+//! Generates TypeScript definitions for the enums in this crate.
+//! 
+//! Each enum is emitted as a constant object mapping variant names to their serialized values,
+//! along with a union type of those values. Enums serialized by their repr have integer values
+//! and other enums have string values, the same as their serde representations. Each enum also
+//! has lookup maps between values and names, and between values and colors for enums which
+//! implement [`Colored`].
+//! 
+//! # Examples
+//! ```
+//! use tf2_enum::Wear;
+//! use tf2_enum::typescript::EnumDefinition;
+//! 
+//! let definition = EnumDefinition::of::<Wear>().render();
+//! 
+//! assert!(definition.contains("export const Wear = {\n    FactoryNew: 1,"));
+//! assert!(definition.contains("export type Wear = typeof Wear[keyof typeof Wear];"));
+//! assert!(definition.contains("    [Wear.FieldTested]: \"Field-Tested\","));
+//! ```
+
+use crate::Colored;
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+use serde::Serialize;
+use strum::{EnumMessage, IntoEnumIterator};
+
+/// The header at the start of generated files.
+const HEADER: &str = "// Generated by tf2-enum. Do not edit.\n";
+
+/// A variant of an [`EnumDefinition`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    /// The name of the variant in Rust, e.g. "FactoryNew".
+    pub ident: String,
+    /// The serialized value, either an integer or a string.
+    pub value: serde_json::Value,
+    /// The display name, e.g. "Factory New".
+    pub name: String,
+    /// Other names accepted when parsing the variant.
+    pub aliases: Vec<&'static str>,
+    /// The color, if the enum is associated with colors.
+    pub color: Option<u32>,
+}
+
+/// The TypeScript definition of an enum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDefinition {
+    /// The name of the enum.
+    pub name: &'static str,
+    /// The variants.
+    pub variants: Vec<Variant>,
+}
+
+impl EnumDefinition {
+    /// Gets the definition of an enum.
+    pub fn of<T>() -> Self
+    where
+        T: IntoEnumIterator + EnumMessage + Serialize + fmt::Display + fmt::Debug,
+    {
+        let variants = T::iter()
+            .map(|value| {
+                let name = value.to_string();
+                let aliases = value
+                    .get_serializations()
+                    .iter()
+                    .copied()
+                    .filter(|alias| *alias != name)
+                    .collect();
+                
+                Variant {
+                    ident: format!("{value:?}"),
+                    value: serde_json::to_value(value).expect("enums are always serializable"),
+                    name,
+                    aliases,
+                    color: None,
+                }
+            })
+            .collect();
+        
+        Self {
+            name: type_name::<T>(),
+            variants,
+        }
+    }
+    
+    /// Gets the definition of an enum with the color of each variant.
+    pub fn of_colored<T>() -> Self
+    where
+        T: IntoEnumIterator + EnumMessage + Serialize + fmt::Display + fmt::Debug + Colored,
+    {
+        let mut definition = Self::of::<T>();
+        
+        for (value, variant) in T::iter().zip(&mut definition.variants) {
+            variant.color = Some(value.color());
+        }
+        
+        definition
+    }
+    
+    /// Renders the TypeScript definition.
+    pub fn render(&self) -> String {
+        let name = self.name;
+        let mut ts = String::new();
+        
+        writeln!(ts, "export const {name} = {{").unwrap();
+        
+        for variant in &self.variants {
+            writeln!(ts, "    {}: {},", variant.ident, variant.value).unwrap();
+        }
+        
+        writeln!(ts, "}} as const;\n").unwrap();
+        writeln!(ts, "export type {name} = typeof {name}[keyof typeof {name}];\n").unwrap();
+        writeln!(ts, "export const {name}Names: Record<{name}, string> = {{").unwrap();
+        
+        for variant in &self.variants {
+            writeln!(ts, "    [{name}.{}]: {},", variant.ident, quote(&variant.name)).unwrap();
+        }
+        
+        writeln!(ts, "}};\n").unwrap();
+        writeln!(ts, "export const {name}ByName: Record<string, {name}> = {{").unwrap();
+        
+        // Some variants share names, in which case the first variant is used.
+        let mut seen = HashSet::new();
+        
+        for variant in &self.variants {
+            for alias in std::iter::once(variant.name.as_str()).chain(variant.aliases.iter().copied()) {
+                if seen.insert(alias) {
+                    writeln!(ts, "    {}: {name}.{},", quote(alias), variant.ident).unwrap();
+                }
+            }
+        }
+        
+        writeln!(ts, "}};").unwrap();
+        
+        if self.variants.iter().all(|variant| variant.color.is_some()) {
+            writeln!(ts, "\nexport const {name}Colors: Record<{name}, string> = {{").unwrap();
+            
+            for variant in &self.variants {
+                if let Some(color) = variant.color {
+                    writeln!(ts, "    [{name}.{}]: \"#{color:06X}\",", variant.ident).unwrap();
+                }
+            }
+            
+            writeln!(ts, "}};").unwrap();
+        }
+        
+        ts
+    }
+}
+
+/// Gets the definitions of every enum.
+pub fn definitions() -> Vec<EnumDefinition> {
+    macro_rules! definitions {
+        // Enums associated with colors.
+        (@definition Grade) => { EnumDefinition::of_colored::<crate::Grade>() };
+        (@definition Paint) => { EnumDefinition::of_colored::<crate::Paint>() };
+        (@definition Quality) => { EnumDefinition::of_colored::<crate::Quality>() };
+        (@definition Sheen) => { EnumDefinition::of_colored::<crate::Sheen>() };
+        (@definition $t:ident) => { EnumDefinition::of::<crate::$t>() };
+        ($($t:ident => $name:literal),* $(,)?) => {
+            vec![$(definitions!(@definition $t)),*]
+        };
+    }
+    
+    for_each_enum!(definitions)
+}
+
+/// Renders the definitions of every enum as a TypeScript module.
+pub fn render() -> String {
+    let definitions = definitions()
+        .iter()
+        .map(EnumDefinition::render)
+        .collect::<Vec<_>>();
+    
+    format!("{HEADER}\n{}", definitions.join("\n"))
+}
+
+/// Gets the name of a type without its path.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Quotes a string as a TypeScript string literal.
+fn quote(s: &str) -> String {
+    serde_json::to_string(s).expect("strings are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ItemSlot, KillEaterScoreType, Quality};
+    
+    #[test]
+    fn renders_repr_enum() {
+        let ts = EnumDefinition::of_colored::<Quality>().render();
+        
+        assert!(ts.contains("export const Quality = {\n    Normal: 0,\n    Genuine: 1,"));
+        assert!(ts.contains("    [Quality.Unusual]: \"Unusual\","));
+        assert!(ts.contains("    \"Unusual\": Quality.Unusual,"));
+        assert!(ts.contains("export const QualityColors: Record<Quality, string> = {"));
+        assert!(ts.contains("    [Quality.Unique]: \"#FFD700\","));
+    }
+    
+    #[test]
+    fn renders_string_enum() {
+        let ts = EnumDefinition::of::<ItemSlot>().render();
+        
+        assert!(ts.contains("    PDA2: \"pda2\","));
+        assert!(ts.contains("    Primary: \"primary\","));
+        assert!(!ts.contains("ItemSlotColors"));
+    }
+    
+    #[test]
+    fn includes_aliases() {
+        let ts = EnumDefinition::of::<KillEaterScoreType>().render();
+        
+        assert!(ts.contains("    \"Übers\": KillEaterScoreType.Ubers,\n    \"Ubers\": KillEaterScoreType.Ubers,"));
+    }
+    
+    #[test]
+    fn names_are_unique() {
+        let definitions = definitions();
+        let names = definitions.iter().map(|definition| definition.name).collect::<HashSet<_>>();
+        
+        assert_eq!(names.len(), definitions.len());
+        
+        for definition in &definitions {
+            let values = definition.variants
+                .iter()
+                .map(|variant| variant.value.to_string())
+                .collect::<HashSet<_>>();
+            
+            assert_eq!(values.len(), definition.variants.len(), "{} has duplicate values", definition.name);
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use super::{parse, Object, Value};
+use crate::error::VdfError;
+use std::collections::HashMap;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::Deserialize;
+
+/// Parses KeyValues text using the default [`ParseOptions`][`super::ParseOptions`] and
+/// deserializes the root object.
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, VdfError> {
+    from_object(&parse(input)?)
+}
+
+/// Deserializes an object.
+/// 
+/// Since KeyValues has no types, values are converted on demand:
+/// - Numbers and booleans (`"1"`, `"0"`, `"true"`, `"false"`) are parsed from strings.
+/// - Unit enum variants are deserialized from strings.
+/// - Keys which are duplicated deserialize into a sequence, or into a single merged map if every
+///   value is an object. Otherwise, the last value is used.
+/// - An object deserializes into a sequence of its values, allowing the common `"0" {} "1" {}`
+///   list idiom, and a single string deserializes into a sequence containing only itself.
+pub fn from_object<'de, T: Deserialize<'de>>(object: &'de Object) -> Result<T, VdfError> {
+    T::deserialize(ObjectDeserializer(object.entries().iter().collect()))
+}
+
+/// Deserializes a value.
+pub fn from_value<'de, T: Deserialize<'de>>(value: &'de Value) -> Result<T, VdfError> {
+    T::deserialize(ValueDeserializer(vec![value]))
+}
+
+/// Entries of one or more objects which are treated as a single map.
+struct ObjectDeserializer<'de>(Vec<&'de (String, Value)>);
+
+impl<'de> ObjectDeserializer<'de> {
+    fn into_map_access(self, group: bool) -> MapAccess<'de> {
+        let entries = if group {
+            // Groups the values for duplicate keys while preserving the order keys first appear in.
+            let mut indices = HashMap::<&str, usize>::new();
+            let mut entries: Vec<(&str, Vec<&Value>)> = Vec::new();
+            
+            for (key, value) in self.0 {
+                match indices.get(key.as_str()) {
+                    Some(index) => entries[*index].1.push(value),
+                    None => {
+                        indices.insert(key, entries.len());
+                        entries.push((key, vec![value]));
+                    },
+                }
+            }
+            
+            entries
+        } else {
+            self.0
+                .into_iter()
+                .map(|(key, value)| (key.as_str(), vec![value]))
+                .collect()
+        };
+        
+        MapAccess {
+            entries: entries.into_iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ObjectDeserializer<'de> {
+    type Error = VdfError;
+    
+    // Duplicate keys are kept when deserializing self-describing types such as `Value`.
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_map(self.into_map_access(false))
+    }
+    
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_map(self.into_map_access(true))
+    }
+    
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        self.deserialize_map(visitor)
+    }
+    
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_some(self)
+    }
+    
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        visitor.visit_newtype_struct(self)
+    }
+    
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct enum identifier ignored_any
+    }
+}
+
+struct MapAccess<'de> {
+    entries: std::vec::IntoIter<(&'de str, Vec<&'de Value>)>,
+    value: Option<Vec<&'de Value>>,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'de> {
+    type Error = VdfError;
+    
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, VdfError> {
+        let Some((key, values)) = self.entries.next() else {
+            return Ok(None);
+        };
+        
+        self.value = Some(values);
+        seed.deserialize(StrDeserializer(key)).map(Some)
+    }
+    
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, VdfError> {
+        let values = self.value
+            .take()
+            .ok_or_else(|| VdfError::Message("value is missing".into()))?;
+        
+        seed.deserialize(ValueDeserializer(values))
+    }
+    
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct SeqAccess<'de> {
+    values: std::vec::IntoIter<&'de Value>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
+    type Error = VdfError;
+    
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, VdfError> {
+        self.values
+            .next()
+            .map(|value| seed.deserialize(ValueDeserializer(vec![value])))
+            .transpose()
+    }
+    
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+/// One or more values for the same key.
+struct ValueDeserializer<'de>(Vec<&'de Value>);
+
+impl<'de> ValueDeserializer<'de> {
+    fn objects(&self) -> Option<Vec<&'de Object>> {
+        self.0.iter().map(|value| value.as_object()).collect()
+    }
+    
+    fn last_str(&self) -> Result<&'de str, VdfError> {
+        self.0
+            .iter()
+            .rev()
+            .find_map(|value| value.as_str())
+            .ok_or_else(|| VdfError::Message("expected a string, found an object".into()))
+    }
+}
+
+macro_rules! forward_to_str {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+                StrDeserializer(self.last_str()?).$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = VdfError;
+    
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        if self.0.len() > 1 {
+            if let Some(objects) = self.objects() {
+                return ObjectDeserializer(objects.into_iter().flat_map(|object| object.entries()).collect())
+                    .deserialize_any(visitor);
+            }
+            
+            return self.deserialize_seq(visitor);
+        }
+        
+        match self.0.first() {
+            Some(Value::Object(object)) => {
+                ObjectDeserializer(object.entries().iter().collect()).deserialize_any(visitor)
+            },
+            Some(Value::String(value)) => visitor.visit_borrowed_str(value),
+            None => visitor.visit_unit(),
+        }
+    }
+    
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        let values = match self.0.as_slice() {
+            [Value::Object(object)] => object.entries().iter().map(|(_, value)| value).collect(),
+            _ => self.0,
+        };
+        
+        visitor.visit_seq(SeqAccess {
+            values: values.into_iter(),
+        })
+    }
+    
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        let objects = self.objects()
+            .ok_or_else(|| VdfError::Message("expected an object, found a string".into()))?;
+        
+        ObjectDeserializer(objects.into_iter().flat_map(|object| object.entries()).collect())
+            .deserialize_map(visitor)
+    }
+    
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        self.deserialize_map(visitor)
+    }
+    
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_some(self)
+    }
+    
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        visitor.visit_newtype_struct(self)
+    }
+    
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, VdfError> {
+        self.deserialize_seq(visitor)
+    }
+    
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        self.deserialize_seq(visitor)
+    }
+    
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        visitor.visit_enum(self.last_str()?.into_deserializer())
+    }
+    
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_unit()
+    }
+    
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        visitor.visit_unit()
+    }
+    
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_unit()
+    }
+    
+    forward_to_str! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf deserialize_identifier
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+                let value = self.0
+                    .trim()
+                    .parse()
+                    .map_err(|_| VdfError::Message(format!("invalid number `{}`", self.0)))?;
+                
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+/// A string, which is also used for keys.
+struct StrDeserializer<'de>(&'de str);
+
+impl<'de> de::Deserializer<'de> for StrDeserializer<'de> {
+    type Error = VdfError;
+    
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_borrowed_str(self.0)
+    }
+    
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        match self.0.trim() {
+            "1" => visitor.visit_bool(true),
+            "0" | "" => visitor.visit_bool(false),
+            value if value.eq_ignore_ascii_case("true") => visitor.visit_bool(true),
+            value if value.eq_ignore_ascii_case("false") => visitor.visit_bool(false),
+            value => Err(VdfError::Message(format!("invalid boolean `{value}`"))),
+        }
+    }
+    
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+    
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, VdfError> {
+        visitor.visit_some(self)
+    }
+    
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        visitor.visit_newtype_struct(self)
+    }
+    
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, VdfError> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+    
+    serde::forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Class, ItemSlot};
+    use std::collections::BTreeMap;
+    
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        name: String,
+        item_slot: Option<ItemSlot>,
+        #[serde(default)]
+        hidden: bool,
+        #[serde(default)]
+        used_by_classes: BTreeMap<Class, String>,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+    
+    #[test]
+    fn deserializes_struct() {
+        let items: BTreeMap<u32, Item> = from_str(r#"
+            "0"
+            {
+                "name" "TF_WEAPON_BAT"
+                "item_slot" "melee"
+                "hidden" "1"
+                "used_by_classes" { "Scout" "1" }
+                "tags" "a"
+                "tags" "b"
+            }
+            "1" { "name" "Hat" "tags" "a" }
+        "#).unwrap();
+        
+        assert_eq!(items[&0], Item {
+            name: "TF_WEAPON_BAT".into(),
+            item_slot: Some(ItemSlot::Melee),
+            hidden: true,
+            used_by_classes: [(Class::Scout, "1".into())].into(),
+            tags: vec!["a".into(), "b".into()],
+        });
+        assert_eq!(items[&1].tags, vec!["a".to_string()]);
+        assert!(!items[&1].hidden);
+    }
+    
+    #[test]
+    fn merges_duplicate_objects() {
+        let map: BTreeMap<String, BTreeMap<String, u32>> = from_str(r#"
+            "attributes" { "a" "1" }
+            "attributes" { "b" "2" }
+        "#).unwrap();
+        
+        assert_eq!(map["attributes"].len(), 2);
+        assert_eq!(map["attributes"]["b"], 2);
+    }
+    
+    #[test]
+    fn deserializes_value() {
+        let input = r#""a" { "b" "c" "b" "d" }"#;
+        let value = from_str::<Value>(input).unwrap();
+        
+        assert_eq!(value, Value::Object(parse(input).unwrap()));
+    }
+    
+    #[test]
+    fn rejects_invalid_numbers() {
+        assert!(from_str::<BTreeMap<String, u32>>(r#""a" "b""#).is_err());
+    }
+}
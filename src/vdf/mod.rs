@@ -0,0 +1,299 @@
+//! Parser for Valve's KeyValues (VDF) text format, as used by `items_game.txt`.
+//! 
+//! Requires the `schema` feature. Handles the quirks found in TF2's files:
+//! - Quoted and unquoted tokens, with the escape sequences `\n`, `\t`, `\\`, and `\"`.
+//! - `//` comments.
+//! - Conditionals such as `[$WIN32]` or `[!$X360 && !$PS3]`, evaluated against
+//!   [`ParseOptions::conditions`].
+//! - `#base` and `#include` directives, resolved relative to [`ParseOptions::include_dir`].
+//! - Duplicate keys, which are preserved in order.
+//! 
+//! Values can be deserialized into any type implementing [`serde::Deserialize`] using
+//! [`from_str`] or [`from_object`].
+//! 
+//! # Examples
+//! ```
+//! use tf2_enum::vdf;
+//! use tf2_enum::ItemSlot;
+//! use serde::Deserialize;
+//! 
+//! #[derive(Deserialize)]
+//! struct Item {
+//!     name: String,
+//!     item_slot: ItemSlot,
+//!     min_ilevel: u32,
+//! }
+//! 
+//! let input = r#"
+//!     "item"
+//!     {
+//!         "name"       "TF_WEAPON_BAT"
+//!         "item_slot"  "melee"
+//!         "min_ilevel" "1"
+//!         "model"      "models/weapons/c_models/c_bat.mdl" [$WIN32]
+//!     }
+//! "#;
+//! let object = vdf::parse(input).unwrap();
+//! let item: Item = vdf::from_object(object.get_object("item").unwrap()).unwrap();
+//! 
+//! assert_eq!(item.name, "TF_WEAPON_BAT");
+//! assert_eq!(item.item_slot, ItemSlot::Melee);
+//! assert_eq!(item.min_ilevel, 1);
+//! ```
+
+mod de;
+mod parser;
+
+pub use de::{from_object, from_str, from_value};
+pub use parser::{parse, parse_file, parse_with, ParseOptions};
+
+use std::fmt;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+
+/// A KeyValues value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Value {
+    /// A string value.
+    String(String),
+    /// A nested object.
+    Object(Object),
+}
+
+impl Value {
+    /// Gets the value as a string, if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            Self::Object(_) => None,
+        }
+    }
+    
+    /// Gets the value as an object, if it is an object.
+    pub fn as_object(&self) -> Option<&Object> {
+        match self {
+            Self::String(_) => None,
+            Self::Object(object) => Some(object),
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Object> for Value {
+    fn from(object: Object) -> Self {
+        Self::Object(object)
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ValueVisitor;
+        
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = Value;
+            
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string or an object")
+            }
+            
+            fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+                Ok(Value::String(value.into()))
+            }
+            
+            fn visit_map<A>(self, map: A) -> Result<Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                ObjectVisitor.visit_map(map).map(Value::Object)
+            }
+        }
+        
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+/// An ordered list of key-value pairs. Duplicate keys are preserved.
+/// 
+/// Keys in KeyValues are case-insensitive, so lookups ignore ASCII case.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Object(Vec<(String, Value)>);
+
+impl Object {
+    /// Creates an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Gets the first value for a key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+    }
+    
+    /// Gets the first value for a key if it is a string.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .filter(|(k, _)| k.eq_ignore_ascii_case(key))
+            .find_map(|(_, value)| value.as_str())
+    }
+    
+    /// Gets the first value for a key if it is an object.
+    pub fn get_object(&self, key: &str) -> Option<&Object> {
+        self.0
+            .iter()
+            .filter(|(k, _)| k.eq_ignore_ascii_case(key))
+            .find_map(|(_, value)| value.as_object())
+    }
+    
+    /// Gets all values for a key, in order.
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a Value> + 'a {
+        self.0
+            .iter()
+            .filter(move |(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+    }
+    
+    /// Checks if the object contains a key.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+    
+    /// Appends a key-value pair. Existing values for the key are kept.
+    pub fn push<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        self.0.push((key.into(), value.into()));
+    }
+    
+    /// Iterates over the key-value pairs in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+    
+    /// The number of key-value pairs, including duplicates.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    
+    /// Checks if the object is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    
+    /// Merges `other` into this object without overwriting. Keys which are missing are appended,
+    /// and keys which are objects in both are merged recursively. This is how `#base` files and
+    /// prefabs are applied.
+    pub fn merge_missing(&mut self, other: &Object) {
+        for (key, value) in &other.0 {
+            let existing = self.0
+                .iter_mut()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key));
+            
+            match (existing, value) {
+                (Some((_, Value::Object(existing))), Value::Object(value)) => {
+                    existing.merge_missing(value);
+                },
+                (Some(_), _) => {},
+                (None, value) => self.0.push((key.clone(), value.clone())),
+            }
+        }
+    }
+    
+    pub(crate) fn entries(&self) -> &[(String, Value)] {
+        &self.0
+    }
+}
+
+impl Extend<(String, Value)> for Object {
+    fn extend<T: IntoIterator<Item = (String, Value)>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl FromIterator<(String, Value)> for Object {
+    fn from_iter<T: IntoIterator<Item = (String, Value)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Object {
+    type Item = (String, Value);
+    type IntoIter = std::vec::IntoIter<(String, Value)>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+struct ObjectVisitor;
+
+impl<'de> Visitor<'de> for ObjectVisitor {
+    type Value = Object;
+    
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object")
+    }
+    
+    fn visit_map<A>(self, mut map: A) -> Result<Object, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut object = Object::new();
+        
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            object.push(key, value);
+        }
+        
+        Ok(object)
+    }
+}
+
+impl<'de> Deserialize<'de> for Object {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ObjectVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn merges_missing() {
+        let mut object = parse(r#""a" "1" "b" { "c" "2" }"#).unwrap();
+        let base = parse(r#""a" "3" "b" { "c" "4" "d" "5" } "e" "6""#).unwrap();
+        
+        object.merge_missing(&base);
+        
+        assert_eq!(object, parse(r#""a" "1" "b" { "c" "2" "d" "5" } "e" "6""#).unwrap());
+    }
+    
+    #[test]
+    fn gets_case_insensitive() {
+        let object = parse(r#""Name" "Scattergun" "name" "Duplicate""#).unwrap();
+        
+        assert_eq!(object.get_str("NAME"), Some("Scattergun"));
+        assert_eq!(object.get_all("name").count(), 2);
+    }
+}
@@ -0,0 +1,505 @@
+use super::{Object, Value};
+use crate::error::VdfError;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// The maximum depth of nested objects.
+const MAX_DEPTH: usize = 256;
+/// The maximum depth of nested `#base` and `#include` directives.
+const MAX_INCLUDE_DEPTH: usize = 32;
+
+/// Options for parsing KeyValues.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The conditions which are defined, without the leading `$`, e.g. `"WIN32"`. Entries
+    /// followed by a conditional which evaluates to false are dropped. Comparisons ignore ASCII
+    /// case. Defaults to `WIN32` and `WINDOWS`.
+    pub conditions: Vec<String>,
+    /// The directory that `#base` and `#include` paths are resolved from. If this is `None`, any
+    /// directive results in [`VdfError::UnresolvedInclude`].
+    pub include_dir: Option<PathBuf>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            conditions: vec!["WIN32".into(), "WINDOWS".into()],
+            include_dir: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    fn evaluate(&self, conditional: &str) -> bool {
+        // e.g. "$WIN32||$OSX" or "!$X360&&!$PS3"
+        conditional
+            .split("||")
+            .any(|any| any.split("&&").all(|term| {
+                let term = term.trim();
+                let (negated, term) = match term.strip_prefix('!') {
+                    Some(term) => (true, term.trim_start()),
+                    None => (false, term),
+                };
+                let name = term.trim_start_matches('$');
+                let defined = self.conditions
+                    .iter()
+                    .any(|condition| condition.eq_ignore_ascii_case(name));
+                
+                defined != negated
+            }))
+    }
+}
+
+/// Parses KeyValues text using the default [`ParseOptions`].
+pub fn parse(input: &str) -> Result<Object, VdfError> {
+    parse_with(input, &ParseOptions::default())
+}
+
+/// Parses KeyValues text.
+pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Object, VdfError> {
+    parse_included(input, options, &mut HashSet::new())
+}
+
+/// Parses a KeyValues file. `#base` and `#include` paths are resolved relative to the file's
+/// directory, overriding [`ParseOptions::include_dir`].
+pub fn parse_file<P: AsRef<Path>>(path: P, options: &ParseOptions) -> Result<Object, VdfError> {
+    parse_file_included(path.as_ref(), options, &mut HashSet::new())
+}
+
+/// Parses KeyValues text, where `visiting` holds the canonical paths of the files currently being
+/// included.
+fn parse_included(
+    input: &str,
+    options: &ParseOptions,
+    visiting: &mut HashSet<PathBuf>,
+) -> Result<Object, VdfError> {
+    let mut parser = Parser {
+        lexer: Lexer::new(input),
+        options,
+        includes: Vec::new(),
+        depth: 0,
+    };
+    let mut object = parser.parse_object(true)?;
+    
+    for (directive, path) in parser.includes {
+        let Some(include_dir) = &options.include_dir else {
+            return Err(VdfError::UnresolvedInclude(path));
+        };
+        let included = parse_file_included(&include_dir.join(&path), options, visiting)?;
+        
+        match directive {
+            Directive::Base => object.merge_missing(&included),
+            Directive::Include => object.extend(included),
+        }
+    }
+    
+    Ok(object)
+}
+
+fn parse_file_included(
+    path: &Path,
+    options: &ParseOptions,
+    visiting: &mut HashSet<PathBuf>,
+) -> Result<Object, VdfError> {
+    let path = path.canonicalize()?;
+    
+    if visiting.contains(&path) {
+        return Err(VdfError::IncludeCycle(path));
+    }
+    
+    if visiting.len() >= MAX_INCLUDE_DEPTH {
+        return Err(VdfError::IncludeTooDeep(path));
+    }
+    
+    let input = std::fs::read_to_string(&path)?;
+    let options = ParseOptions {
+        conditions: options.conditions.clone(),
+        include_dir: path.parent().map(Path::to_path_buf),
+    };
+    
+    visiting.insert(path.clone());
+    
+    // The path is removed afterwards so that files included more than once without a cycle are
+    // still allowed.
+    let object = parse_included(&input, &options, visiting);
+    
+    visiting.remove(&path);
+    object
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Directive {
+    Base,
+    Include,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    String(String),
+    // Only unquoted tokens are treated as directives.
+    Unquoted(String),
+    Conditional(String),
+    Open,
+    Close,
+}
+
+struct Lexer<'a> {
+    input: &'a str,
+    position: usize,
+    line: usize,
+    column: usize,
+    peeked: Option<Option<Token>>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            // Files exported by some tools start with a byte order mark.
+            input: input.strip_prefix('\u{feff}').unwrap_or(input),
+            position: 0,
+            line: 1,
+            column: 1,
+            peeked: None,
+        }
+    }
+    
+    fn error(&self, message: impl Into<String>) -> VdfError {
+        VdfError::Syntax {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+    
+    fn peek_char(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+    
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        
+        self.position += c.len_utf8();
+        
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        
+        Some(c)
+    }
+    
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            match self.peek_char() {
+                Some(c) if c.is_whitespace() => {
+                    self.next_char();
+                },
+                Some('/') if self.input[self.position..].starts_with("//") => {
+                    while !matches!(self.next_char(), Some('\n') | None) {}
+                },
+                _ => break,
+            }
+        }
+    }
+    
+    fn peek(&mut self) -> Result<Option<&Token>, VdfError> {
+        if self.peeked.is_none() {
+            let token = self.read_token()?;
+            
+            self.peeked = Some(token);
+        }
+        
+        Ok(self.peeked.as_ref().and_then(Option::as_ref))
+    }
+    
+    fn next(&mut self) -> Result<Option<Token>, VdfError> {
+        match self.peeked.take() {
+            Some(token) => Ok(token),
+            None => self.read_token(),
+        }
+    }
+    
+    fn read_token(&mut self) -> Result<Option<Token>, VdfError> {
+        self.skip_whitespace_and_comments();
+        
+        let Some(c) = self.next_char() else {
+            return Ok(None);
+        };
+        let token = match c {
+            '{' => Token::Open,
+            '}' => Token::Close,
+            '"' => Token::String(self.read_quoted()?),
+            '[' => {
+                let mut conditional = String::new();
+                
+                loop {
+                    match self.next_char() {
+                        Some(']') => break,
+                        Some('\n') | None => return Err(self.error("unterminated conditional")),
+                        Some(c) => conditional.push(c),
+                    }
+                }
+                
+                Token::Conditional(conditional)
+            },
+            c => {
+                let mut token = String::from(c);
+                
+                while let Some(c) = self.peek_char() {
+                    if c.is_whitespace() || matches!(c, '"' | '{' | '}') {
+                        break;
+                    }
+                    
+                    token.push(c);
+                    self.next_char();
+                }
+                
+                Token::Unquoted(token)
+            },
+        };
+        
+        Ok(Some(token))
+    }
+    
+    fn read_quoted(&mut self) -> Result<String, VdfError> {
+        let mut value = String::new();
+        
+        loop {
+            match self.next_char() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.next_char() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('\\') => value.push('\\'),
+                    Some('"') => value.push('"'),
+                    // Unknown escapes are kept as-is, e.g. Windows paths.
+                    Some(c) => {
+                        value.push('\\');
+                        value.push(c);
+                    },
+                    None => return Err(self.error("unterminated string")),
+                },
+                Some(c) => value.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+}
+
+struct Parser<'a, 'o> {
+    lexer: Lexer<'a>,
+    options: &'o ParseOptions,
+    includes: Vec<(Directive, String)>,
+    depth: usize,
+}
+
+impl Parser<'_, '_> {
+    /// Parses key-value pairs until a closing brace, or the end of input if `root` is true.
+    fn parse_object(&mut self, root: bool) -> Result<Object, VdfError> {
+        let mut object = Object::new();
+        
+        loop {
+            let key = match self.lexer.next()? {
+                None if root => return Ok(object),
+                None => return Err(self.lexer.error("expected `}`")),
+                Some(Token::Close) if !root => return Ok(object),
+                Some(Token::Close) => return Err(self.lexer.error("unexpected `}`")),
+                Some(Token::Open) => return Err(self.lexer.error("unexpected `{`")),
+                Some(Token::Conditional(_)) => return Err(self.lexer.error("unexpected conditional")),
+                Some(Token::Unquoted(key)) if root && key.starts_with('#') => {
+                    let directive = match key.to_ascii_lowercase().as_str() {
+                        "#base" => Directive::Base,
+                        "#include" => Directive::Include,
+                        _ => return Err(self.lexer.error(format!("unknown directive `{key}`"))),
+                    };
+                    let path = match self.lexer.next()? {
+                        Some(Token::String(path) | Token::Unquoted(path)) => path,
+                        _ => return Err(self.lexer.error("expected a path")),
+                    };
+                    
+                    self.includes.push((directive, path));
+                    continue;
+                },
+                Some(Token::String(key) | Token::Unquoted(key)) => key,
+            };
+            let mut condition = self.conditional()?;
+            let value = match self.lexer.next()? {
+                Some(Token::String(value) | Token::Unquoted(value)) => Value::String(value),
+                Some(Token::Open) => {
+                    if self.depth >= MAX_DEPTH {
+                        return Err(self.lexer.error("objects are nested too deeply"));
+                    }
+                    
+                    self.depth += 1;
+                    
+                    let object = self.parse_object(false)?;
+                    
+                    self.depth -= 1;
+                    Value::Object(object)
+                },
+                _ => return Err(self.lexer.error(format!("expected a value for `{key}`"))),
+            };
+            
+            if condition.is_none() {
+                condition = self.conditional()?;
+            }
+            
+            if condition.map_or(true, |condition| self.options.evaluate(&condition)) {
+                object.push(key, value);
+            }
+        }
+    }
+    
+    fn conditional(&mut self) -> Result<Option<String>, VdfError> {
+        if let Some(Token::Conditional(_)) = self.lexer.peek()? {
+            if let Some(Token::Conditional(conditional)) = self.lexer.next()? {
+                return Ok(Some(conditional));
+            }
+        }
+        
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_nested_objects() {
+        let object = parse(r#"
+            // A comment.
+            "items_game"
+            {
+                "items"
+                {
+                    "0" { "name" "TF_WEAPON_BAT" } // Trailing comment.
+                    unquoted value
+                }
+            }
+        "#).unwrap();
+        let items = object.get_object("items_game").unwrap().get_object("items").unwrap();
+        
+        assert_eq!(items.get_object("0").unwrap().get_str("name"), Some("TF_WEAPON_BAT"));
+        assert_eq!(items.get_str("unquoted"), Some("value"));
+    }
+    
+    #[test]
+    fn parses_escape_sequences() {
+        let object = parse(r#""key" "a \"quoted\"\nline\\ C:\path""#).unwrap();
+        
+        assert_eq!(object.get_str("key"), Some("a \"quoted\"\nline\\ C:\\path"));
+    }
+    
+    #[test]
+    fn evaluates_conditionals() {
+        let input = r#"
+            "a" "windows" [$WIN32]
+            "a" "xbox" [$X360]
+            "b" "not xbox" [!$X360]
+            "c" [$OSX||$WIN32] { "d" "1" }
+            "e" "both" [$WIN32&&$X360]
+        "#;
+        let object = parse(input).unwrap();
+        
+        assert_eq!(object.get_all("a").count(), 1);
+        assert_eq!(object.get_str("a"), Some("windows"));
+        assert_eq!(object.get_str("b"), Some("not xbox"));
+        assert!(object.contains_key("c"));
+        assert!(!object.contains_key("e"));
+        
+        let options = ParseOptions {
+            conditions: vec!["X360".into()],
+            ..Default::default()
+        };
+        let object = parse_with(input, &options).unwrap();
+        
+        assert_eq!(object.get_str("a"), Some("xbox"));
+        assert!(!object.contains_key("b"));
+    }
+    
+    #[test]
+    fn keeps_duplicate_keys() {
+        let object = parse(r#""key" "1" "key" "2""#).unwrap();
+        let values = object.get_all("key").filter_map(Value::as_str).collect::<Vec<_>>();
+        
+        assert_eq!(values, vec!["1", "2"]);
+    }
+    
+    #[test]
+    fn resolves_includes() {
+        let dir = std::env::temp_dir().join(format!("tf2-enum-vdf-{}", std::process::id()));
+        
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("base.txt"), r#""root" { "a" "base" "b" "base" }"#).unwrap();
+        std::fs::write(dir.join("extra.txt"), r#""extra" "1""#).unwrap();
+        std::fs::write(dir.join("main.txt"), r#"
+            #base "base.txt"
+            #include "extra.txt"
+            "root" { "a" "main" }
+        "#).unwrap();
+        
+        let object = parse_file(dir.join("main.txt"), &ParseOptions::default()).unwrap();
+        let root = object.get_object("root").unwrap();
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+        
+        assert_eq!(root.get_str("a"), Some("main"));
+        assert_eq!(root.get_str("b"), Some("base"));
+        assert_eq!(object.get_str("extra"), Some("1"));
+        assert!(matches!(
+            parse(r#"#base "base.txt""#),
+            Err(VdfError::UnresolvedInclude(path)) if path == "base.txt"
+        ));
+    }
+    
+    #[test]
+    fn rejects_include_cycles() {
+        let dir = std::env::temp_dir().join(format!("tf2-enum-vdf-cycle-{}", std::process::id()));
+        
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("self.txt"), r#"#include "self.txt""#).unwrap();
+        std::fs::write(dir.join("a.txt"), r#"#base "b.txt" "a" "1""#).unwrap();
+        std::fs::write(dir.join("b.txt"), r#"#include "a.txt" "b" "1""#).unwrap();
+        std::fs::write(dir.join("shared.txt"), r#""shared" "1""#).unwrap();
+        std::fs::write(dir.join("diamond.txt"), r#"
+            #base "shared.txt"
+            #include "shared.txt"
+        "#).unwrap();
+        
+        let self_included = parse_file(dir.join("self.txt"), &ParseOptions::default());
+        let mutually_included = parse_file(dir.join("a.txt"), &ParseOptions::default());
+        let diamond = parse_file(dir.join("diamond.txt"), &ParseOptions::default());
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+        
+        assert!(matches!(self_included, Err(VdfError::IncludeCycle(path)) if path.ends_with("self.txt")));
+        assert!(matches!(mutually_included, Err(VdfError::IncludeCycle(path)) if path.ends_with("a.txt")));
+        assert_eq!(diamond.unwrap().get_str("shared"), Some("1"));
+    }
+    
+    #[test]
+    fn rejects_deeply_nested_objects() {
+        let input = "\"a\" {".repeat(100_000);
+        
+        assert!(matches!(
+            parse(&input),
+            Err(VdfError::Syntax { message, .. }) if message == "objects are nested too deeply"
+        ));
+        
+        let input = format!("{}{}", "\"a\" {".repeat(MAX_DEPTH), "}".repeat(MAX_DEPTH));
+        
+        assert!(parse(&input).is_ok());
+    }
+    
+    #[test]
+    fn reports_syntax_errors() {
+        assert!(matches!(
+            parse("\"a\"\n{\n\"b\" \"c\""),
+            Err(VdfError::Syntax { line: 3, .. })
+        ));
+        assert!(matches!(parse(r#""a" "unterminated"#), Err(VdfError::Syntax { .. })));
+    }
+}